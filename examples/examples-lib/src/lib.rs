@@ -3,7 +3,13 @@ use axum::{
     routing::{get, get_service},
     Router,
 };
-use html_strong::{document_tree::Node, science_lab::NodeExt, tags::*, template};
+use html_strong::{
+    csp::{Csp, Directive, Source},
+    document_tree::Node,
+    science_lab::NodeExt,
+    tags::*,
+    template,
+};
 use reqwest::StatusCode;
 use tower_http::services::ServeDir;
 
@@ -60,6 +66,9 @@ impl Example for Home {
     }
 }
 
+/// Let's generally use the same font everywhere.
+const FONT_CSS: &str = "https://fonts.googleapis.com/css2?family=Domine:wght@500&display=swap";
+
 /// Wrap contents of example in a common HTML document template.
 /// This template mainly sets up a default document structure,
 /// and includes the common <nav> (TODO) and <footer> (TODO).
@@ -79,6 +88,10 @@ impl Example for Home {
 /// </body>
 ///
 /// So don't actually pass a `Body`.
+///
+/// The document carries a strict Content Security Policy in a `<meta>` tag,
+/// allowing the given external stylesheets and scripts by their origin,
+/// and every inline `<script>`/`<style>` by a fresh nonce.
 pub fn html_doc<S: AsRef<str>>(
     css: Option<Vec<S>>,
     script: Option<Vec<S>>,
@@ -88,26 +101,28 @@ pub fn html_doc<S: AsRef<str>>(
     // Use html-strong's base head template.
     let mut head = template::head();
 
+    let css: Vec<String> = css
+        .into_iter()
+        .flatten()
+        .map(|css| css.as_ref().to_string())
+        .chain([FONT_CSS.to_string()])
+        .collect();
+    let script: Vec<String> = script
+        .into_iter()
+        .flatten()
+        .map(|script| script.as_ref().to_string())
+        .collect();
+
     // Add stylesheets.
-    if let Some(css) = css {
-        for css in css {
-            head.push_kid(Link::stylesheet(mime::TEXT_CSS, css.as_ref()));
-        }
+    for css in &css {
+        head.push_kid(Link::stylesheet(mime::TEXT_CSS, css));
     }
-
-    // Let's generally use the same font everywhere.
-    head.push_kid(Link::stylesheet(
-        mime::TEXT_CSS,
-        "https://fonts.googleapis.com/css2?family=Domine:wght@500&display=swap",
-    ));
     // Always want the "base CSS" used for the top nav.
     head.push_kid(Link::stylesheet(mime::TEXT_CSS, "/static/example.css"));
 
     // Add scripts.
-    if let Some(script) = script {
-        for script in script {
-            head.push_kid(Script::src(script.as_ref()));
-        }
+    for script in &script {
+        head.push_kid(Script::src(script));
     }
 
     // Add scripts where content is defined inline.
@@ -139,9 +154,48 @@ pub fn html_doc<S: AsRef<str>>(
         .kid(nav.class("example-nav"))
         .kid(body.class("example-body"));
 
-    template::HtmlDocumentBuilder::new()
+    let mut document = template::HtmlDocumentBuilder::new()
         .with_head(head)
         .with_body(body)
         // TODO: <footer>
-        .build()
+        .build();
+
+    // Stylesheets may load fonts from their own origin, e.g. Google's from their static host.
+    let font_origins = css
+        .iter()
+        .filter_map(|css| origin(css))
+        .chain([Source::host("https://fonts.gstatic.com")]);
+
+    Csp::strict()
+        .directive(
+            Directive::StyleSrc,
+            css.iter().filter_map(|css| origin(css)),
+        )
+        .directive(
+            Directive::ScriptSrc,
+            script.iter().filter_map(|script| origin(script)),
+        )
+        .directive(
+            Directive::FontSrc,
+            [Source::SelfOrigin].into_iter().chain(font_origins),
+        )
+        // The examples still set some inline `style` attributes.
+        .directive(Directive::StyleSrcAttr, [Source::UnsafeInline])
+        .with_meta()
+        .apply(&mut document)
+        .expect("Nonces do not need rendering");
+
+    document
+}
+
+/// The origin of an external stylesheet or script, e.g. `https://cdn.jsdelivr.net`.
+/// Local paths are covered by `'self'` and give `None`.
+fn origin(path: &str) -> Option<Source> {
+    let rest = path
+        .strip_prefix("https:")
+        .unwrap_or(path)
+        .strip_prefix("//")?;
+    let host = rest.split('/').next()?;
+
+    Some(Source::host(&format!("https://{host}")))
 }
@@ -15,7 +15,7 @@ pub struct Comment {
     pub parent: usize,
 
     /// Comment contents, can be HTML.
-    /// Must be sanitized in the HN backend, right?
+    /// Untrusted, see [`html_strong::sanitize::Sanitizer::comments`].
     pub text: String,
 
     /// Submission time
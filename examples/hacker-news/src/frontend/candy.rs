@@ -1,5 +1,15 @@
+use std::sync::OnceLock;
+
 use cached::proc_macro::cached;
-use html_strong::{document_tree::Node, science_lab::NodeExt, tags::*};
+use html_strong::{
+    css::{
+        percent, px, rem, Color, Property, PseudoClass, PseudoElement, Rule, ScopedStyle, Selector,
+        Stylesheet,
+    },
+    document_tree::Node,
+    science_lab::NodeExt,
+    tags::*,
+};
 
 use crate::story::Story;
 
@@ -42,33 +52,135 @@ https://dribbble.com/shots/14101951-Banners
 
 */
 
-#[cached]
-fn candy_story(story: Story, class: String) -> Node {
-    let outer = Div.class(format!("card {class}"));
-    let icon = Div.class("card__icon").kid(I.class("fas fa-bolt"));
-    let exit = P.class("card__exit").kid(I.class("fas fa-times"));
-    let title = H2.class("card__title").text(story.title);
-
-    let apply = P.class("card__apply").kid(
-        A::href("#")
-            .class("card__link")
-            .text("Idk go to the comments?")
-            .kid(I.class("fas fa-arrow-right")),
-    );
-
-    outer.kid(icon).kid(exit).kid(title).kid(apply)
+/// The styles of the design above, scoped to this frontend.
+fn style() -> &'static ScopedStyle {
+    static STYLE: OnceLock<ScopedStyle> = OnceLock::new();
+
+    STYLE.get_or_init(|| {
+        ScopedStyle::new("Candy", |scope| {
+            let white = |alpha| Color::Rgba(255, 255, 255, alpha);
+            let backgrounds = [
+                ("#1fe4f5", "#3fbafe"),
+                ("#fbc1cc", "#fa99b2"),
+                ("#76b2fe", "#b69efe"),
+                ("#60efbc", "#58d5c9"),
+                ("#f588d8", "#c0a3e5"),
+            ];
+
+            let sheet = Stylesheet::new()
+                .rule(Rule::new(Selector::tag(&Body)).set(
+                    Property::FontFamily,
+                    r#"-apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, Helvetica, Arial, sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol""#,
+                ))
+                .rule(
+                    Rule::new(scope.selector("cards"))
+                        .set(Property::Display, "flex")
+                        .set(Property::FlexDirection, "column"),
+                )
+                .rule(
+                    Rule::new(scope.selector("card"))
+                        .set(Property::MarginBottom, rem(2))
+                        .set(Property::Padding, rem(2))
+                        .set(Property::MinHeight, px(200))
+                        .set(Property::Display, "grid")
+                        .set(Property::from_name("grid-template-rows"), "20px 50px 1fr 50px")
+                        .set(Property::BorderRadius, px(10))
+                        .set(Property::BoxShadow, "0px 6px 10px rgba(0, 0, 0, 0.25)")
+                        .set(Property::Transition, "all 0.2s"),
+                )
+                .rule(
+                    Rule::new(scope.selector("card").pseudo_class(PseudoClass::Hover))
+                        .set(Property::BoxShadow, "0px 6px 10px rgba(0, 0, 0, 0.4)")
+                        .set(Property::Transform, "scale(1.01)"),
+                )
+                .rule(
+                    Rule::new(scope.selector("card__link"))
+                        .or(scope.selector("card__exit"))
+                        .or(scope.selector("card__icon"))
+                        .set(Property::Position, "relative")
+                        .set(Property::TextDecoration, "none")
+                        .set(Property::Color, white(0.9)),
+                )
+                .rule(
+                    Rule::new(scope.selector("card__link").pseudo_element(PseudoElement::After))
+                        .set(Property::Position, "absolute")
+                        .set(Property::Top, px(25))
+                        .set(Property::Left, 0)
+                        .set(Property::Content, r#""""#)
+                        .set(Property::Width, percent(0))
+                        .set(Property::Height, px(3))
+                        .set(Property::BackgroundColor, white(0.6))
+                        .set(Property::Transition, "all 0.5s"),
+                )
+                .rule(
+                    Rule::new(
+                        scope
+                            .selector("card__link")
+                            .pseudo_class(PseudoClass::Hover)
+                            .pseudo_element(PseudoElement::After),
+                    )
+                    .set(Property::Width, percent(100)),
+                )
+                .rule(
+                    Rule::new(scope.selector("card__exit"))
+                        .set(Property::from_name("grid-row"), "1/2")
+                        .set(Property::from_name("justify-self"), "end"),
+                )
+                .rule(
+                    Rule::new(scope.selector("card__icon"))
+                        .set(Property::from_name("grid-row"), "2/3")
+                        .set(Property::FontSize, px(30)),
+                )
+                .rule(
+                    Rule::new(scope.selector("card__title"))
+                        .set(Property::from_name("grid-row"), "3/4")
+                        .set(Property::FontWeight, 400)
+                        .set(Property::Color, Color::White),
+                )
+                .rule(
+                    Rule::new(scope.selector("card__apply"))
+                        .set(Property::from_name("grid-row"), "4/5")
+                        .set(Property::from_name("align-self"), "center"),
+                );
+
+            backgrounds
+                .iter()
+                .enumerate()
+                .fold(sheet, |sheet, (index, (from, to))| {
+                    sheet.rule(
+                        Rule::new(scope.selector(&format!("card-{index}")))
+                            .set(Property::Background, format!("radial-gradient({from}, {to})")),
+                    )
+                })
+                .media(
+                    "(max-width: 1600px)",
+                    Stylesheet::new().rule(
+                        Rule::new(scope.selector("cards")).set(Property::JustifyContent, "center"),
+                    ),
+                )
+        })
+    })
 }
 
 #[cached]
-fn candy_story2(story: Story, class: String) -> Node {
-    Div.class(format!("card {class}"))
-        .kid(Div.class("card__icon").kid(I.class("fas fa-bolt")))
-        .kid(P.class("card__exit").kid(I.class("fas fa-times")))
-        .kid(H2.class("card__title").text(story.title))
+fn candy_story(story: Story, index: usize) -> Node {
+    let style = style();
+
+    Div.scoped_class(style, "card")
+        .add_scoped_class(style, &format!("card-{index}"))
+        .kid(
+            Div.scoped_class(style, "card__icon")
+                .kid(I.class("fas fa-bolt")),
+        )
+        .kid(
+            P.scoped_class(style, "card__exit")
+                .kid(I.class("fas fa-times")),
+        )
+        .kid(H2.scoped_class(style, "card__title").text(story.title))
         .kid(
-            P.class("card__apply").kid(
+            P.scoped_class(style, "card__apply").kid(
                 A::href("#")
-                    .class("card__link")
+                    .scoped_class(style, "card__link")
                     .text("Idk go to the comments?")
                     .kid(I.class("fas fa-arrow-right")),
             ),
@@ -77,16 +189,16 @@ fn candy_story2(story: Story, class: String) -> Node {
 
 impl Renderable for Candy {
     fn frontpage(&self, stories: Vec<Story>) -> Node {
-        let mut cards = Div.class("cards");
+        let mut cards = Div.scoped_class(style(), "cards");
 
         for (index, story) in stories.into_iter().enumerate() {
-            // The CSS defines card-0 through card-4, which decides colors.
-            cards.push_kid(candy_story(story, format!("card-{}", index % 5)));
+            // The styles define card-0 through card-4, which decides colors.
+            cards.push_kid(candy_story(story, index % 5));
         }
 
+        // The collected scoped styles end up in the head.
         examples_lib::html_doc(
             Some(vec![
-                "/hn/static/candy.css",
                 "https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.1.1/css/all.min.css",
             ]),
             None,
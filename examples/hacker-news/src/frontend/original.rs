@@ -4,11 +4,12 @@ pub struct Original;
 
 use crate::comment::Comment;
 use crate::story::Story;
-use crate::util::time_ago;
 
 use cached::proc_macro::cached;
+use chrono::Local;
 use html_strong::{
-    document_tree::{o, Node},
+    document_tree::Node,
+    sanitize::Sanitizer,
     science_lab::NodeExt,
     tags::{td::td, *},
 };
@@ -74,7 +75,7 @@ fn table_fatitem(story: &Story) -> Node {
     let score_id = &format!("score_{}", story.id);
     let unv_id = &format!("unv_{}", story.id);
     let item_id = &format!("/hn/item?id={}", story.id);
-    let time_ago_href = o(A::href(item_id)).add_text(&time_ago(story.submission_time));
+    let time_ago_href = A::href(item_id).kid(Time::relative(&story.submission_time, &Local::now()));
 
     let score_span = Span
         .class("score")
@@ -85,7 +86,7 @@ fn table_fatitem(story: &Story) -> Node {
         .class("hnuser")
         .text(&story.author);
 
-    let age_span = Span.class("age").set_title("2022-TODO").kid(time_ago_href);
+    let age_span = Span.class("age").kid(time_ago_href);
 
     let unv_span = Span.id(unv_id);
     let hide_a = A::href("TODO").text("hide");
@@ -137,7 +138,7 @@ fn table_fatitem(story: &Story) -> Node {
                 ),
         )
         .kid(
-            Tr.kid(Td::colspan(2)).kid(
+            Tr.kid(Td::colspan(2).unwrap()).kid(
                 td().class("subtext")
                     .kid(score_span)
                     .text(" by ")
@@ -158,7 +159,7 @@ fn table_fatitem(story: &Story) -> Node {
         )
         .kid(Tr.style("height:10px"))
         .kid(
-            Tr.kid(Td::colspan(2)).kid(
+            Tr.kid(Td::colspan(2).unwrap()).kid(
                 td().kid(
                     Form::new(form::Method::Post, "comment")
                         .kid(Input::hidden("parent", &story.id.to_string()))
@@ -196,33 +197,31 @@ fn tr_comment(comment: &Comment) -> Node {
                 id="30897201" n="1" href="javascript:void(0)">[–]</a><span
                 class="onstory"></span> </span></div>
     */
-    let td_default_div_comhead =
-        Div.kid(
-            Span.class("comhead")
-                .kid(
-                    A::href(&format!("user?id={}", comment.author))
-                        .class("hnuser")
-                        .text(&comment.author),
-                )
-                .kid(Span.class("age").set_title(&comment.time.to_string()).kid(
-                    A::href(&format!("/hn/item?id={}", comment.id)).text(time_ago(comment.time)),
-                ))
-                .kid(Span.id(format!("unv_{}", comment.id)))
-                .kid(
-                    Span.class("navs").text(PIPE_DELIMITER).kid(
-                        A::href(&format!("#{}", comment.id))
-                            .class("clicky")
-                            .text("next"),
-                    ), // TODO: aria-hidden
-                )
-                .kid(
-                    A::href("javascript:void(0)")
-                        .class("togg clicky")
-                        .id(&comment.id.to_string())
-                        .text("[-]"), // TODO: n="1", n="<number>", what does it do?
-                )
-                .kid(Span.class("onstory")),
-        );
+    // Clicking the summary collapses the comment, the "[-]" toggle is drawn by news-extra.css.
+    let td_default_summary = Summary.kid(
+        Span.class("comhead")
+            .kid(
+                A::href(&format!("user?id={}", comment.author))
+                    .class("hnuser")
+                    .text(&comment.author),
+            )
+            .kid(
+                Span.class("age").kid(
+                    A::href(&format!("/hn/item?id={}", comment.id))
+                        .kid(Time::relative(&comment.time, &Local::now())),
+                ),
+            )
+            .kid(Span.id(format!("unv_{}", comment.id)))
+            .kid(
+                Span.class("navs").text(PIPE_DELIMITER).kid(
+                    A::href(&format!("#{}", comment.id))
+                        .class("clicky")
+                        .text("next"),
+                ), // TODO: aria-hidden
+            )
+            .kid(Span.class("togg clicky"))
+            .kid(Span.class("onstory")),
+    );
 
     /*
     All of this stuff:
@@ -239,14 +238,19 @@ fn tr_comment(comment: &Comment) -> Node {
         </div>
     */
     let td_default_div_comment = Div
-        .kid(Span.class("commtext c00").text(&comment.text))
+        .class("comment")
+        .kid(
+            Span.class("commtext c00")
+                .kid(Sanitizer::comments().sanitize(&comment.text)),
+        )
         .kid(Div.class("reply").kid(P.kid(U.kid(A::href("TODO"))))); // TODO: <font> is deprecated, add class.
 
-    let td_default = td()
-        .class("default")
-        .kid(td_default_div_comhead)
-        .kid(Br)
-        .kid(td_default_div_comment);
+    let td_default = td().class("default").kid(
+        Details::new()
+            .open()
+            .kid(td_default_summary)
+            .kid(td_default_div_comment),
+    );
 
     Tr.class("athing comtr")
         .id(&comment.id.to_string())
@@ -339,7 +343,7 @@ fn hnstory(story: Story) -> Node {
                 .kid(title),
         )
         .kid(
-            Tr.kid(Td::colspan(2)).kid(
+            Tr.kid(Td::colspan(2).unwrap()).kid(
                 td().class("subtext")
                     .kid(Span.text(format!("{} points", story.upvotes)))
                     .text(" by ")
@@ -350,12 +354,10 @@ fn hnstory(story: Story) -> Node {
                             .text(ONE_SPACE),
                     )
                     .kid(
-                        Span.class("age")
-                            .set_title("2022-03-28T16:35:29") // TODO: This thing
-                            .kid(
-                                A::href(&format!("/hn/item?id={}", story.id))
-                                    .text(time_ago(story.submission_time)),
-                            ),
+                        Span.class("age").kid(
+                            A::href(&format!("/hn/item?id={}", story.id))
+                                .kid(Time::relative(&story.submission_time, &Local::now())),
+                        ),
                     )
                     .kid(Span)
                     .text(PIPE_DELIMITER)
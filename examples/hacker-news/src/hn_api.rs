@@ -74,7 +74,7 @@ pub struct ApiComment {
     pub parent: usize,
 
     /// Comment contents, can be HTML.
-    /// Must be sanitized in the HN backend, right?
+    /// Untrusted, see [`html_strong::sanitize::Sanitizer::comments`].
     pub text: String,
 
     /// Unix time
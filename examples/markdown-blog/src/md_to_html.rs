@@ -45,7 +45,7 @@ fn consume_event(
     baggage: (Vec<Node>, Option<Context>),
     event: Event,
 ) -> (Vec<Node>, Option<Context>) {
-    use pulldown_cmark::{CodeBlockKind, HeadingLevel, LinkType, Tag};
+    use pulldown_cmark::{CodeBlockKind, LinkType, Tag};
 
     let (mut nodes, context) = baggage;
 
@@ -56,13 +56,11 @@ fn consume_event(
         // This means we should be adding something to our stack.
         Event::Start(e) => match e {
             Tag::Paragraph => (stack_add(nodes, P), context),
-            Tag::Heading(level, _, _) => match level {
-                HeadingLevel::H1 => (stack_add(nodes, H1), context),
-                HeadingLevel::H2 => (stack_add(nodes, H2), context),
-                level => {
-                    todo!("Heading level not implememented: {level:?}");
-                }
-            },
+            Tag::Heading(level, _, _) => {
+                let heading =
+                    Heading::level(level as usize).expect("Markdown headings are h1 to h6");
+                (stack_add(nodes, heading), context)
+            }
             Tag::BlockQuote => (stack_add(nodes, Blockquote::new()), context),
             Tag::CodeBlock(CodeBlockKind::Fenced(lang)) => {
                 // This starts a single event, but introduces two elements to the HTML!
@@ -1,2 +1,8 @@
-/// Hyperlinks!
+/// URLs for `href`, `src` and `action`, see [`href::SafeUrl`].
 pub mod href;
+
+/// Subresource Integrity and CORS settings.
+pub mod integrity;
+
+/// Dates, times and durations for `datetime`.
+pub(crate) mod datetime;
@@ -0,0 +1,15 @@
+use crate::global_attributes::{escape_attribute_value, Attribute};
+
+/// The machine-readable `datetime` of a [`crate::tags::Time`], [`crate::tags::Ins`] or [`crate::tags::Del`].
+#[derive(Debug, Clone)]
+pub struct Datetime(pub String);
+
+impl Attribute for Datetime {
+    fn name(&self) -> &'static str {
+        "datetime"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
@@ -1,4 +1,297 @@
-// URL. See [here](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href).
-// pub enum Href {
+use std::fmt::{self, Write};
 
-// }
\ No newline at end of file
+use crate::global_attributes::escape_attribute_value;
+
+/// Image types which may be inlined as `data:` URLs, since they cannot carry scripts.
+/// Notably SVG is not among them.
+const DATA_IMAGE_TYPES: &[&str] = &[
+    "image/avif",
+    "image/bmp",
+    "image/gif",
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+    "image/x-icon",
+];
+
+/// What a blocked URL is replaced with, see [`SafeUrl::sanitize`].
+const BLOCKED: &str = "about:invalid#blocked";
+
+/// A URL was blocked since it may run scripts, see [`SafeUrl`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedUrl {
+    url: String,
+    scheme: String,
+}
+
+impl BlockedUrl {
+    /// The offending scheme, lowercased.
+    #[must_use]
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+}
+
+impl fmt::Display for BlockedUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "blocked URL `{}`: the `{}:` scheme is not allowed",
+            self.url, self.scheme
+        )
+    }
+}
+
+impl std::error::Error for BlockedUrl {}
+
+/// A URL for attributes such as `href`, `src` and `action`.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href).
+///
+/// May be absolute, e.g. from a [`url::Url`], or a relative reference such as `../style.css`,
+/// `?page=2` or `#comments`.
+///
+/// URLs which would run scripts when followed are blocked,
+/// i.e. the `javascript:` and `vbscript:` schemes,
+/// and `data:` except for a few image types.
+/// Conversions via [`From`] replace blocked URLs with a harmless placeholder,
+/// while [`SafeUrl::new`] reports them.
+/// Use [`SafeUrl::trusted`] for URLs known to be fine, e.g. a `data:` URL the server generated.
+///
+/// The URL is escaped when rendered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SafeUrl(String);
+
+impl SafeUrl {
+    /// Check a URL.
+    ///
+    /// # Errors
+    ///
+    /// If the URL's scheme is blocked.
+    pub fn new(url: &str) -> Result<Self, BlockedUrl> {
+        blocked_scheme(url).map_or_else(
+            || Ok(Self(url.to_string())),
+            |scheme| {
+                Err(BlockedUrl {
+                    url: url.to_string(),
+                    scheme,
+                })
+            },
+        )
+    }
+
+    /// Check a URL, replacing it with `about:invalid#blocked` if it is blocked.
+    #[must_use]
+    pub fn sanitize(url: &str) -> Self {
+        Self::new(url).unwrap_or_else(|_| Self(BLOCKED.to_string()))
+    }
+
+    /// A URL which is not checked. Only use this for URLs not influenced by users.
+    #[must_use]
+    pub fn trusted(url: &str) -> Self {
+        Self(url.to_string())
+    }
+
+    /// A reference to a fragment of the current document, e.g. `#comments`.
+    ///
+    /// The fragment is given without `#`, typically the id of an element.
+    #[must_use]
+    pub fn fragment(fragment: &str) -> Self {
+        let mut url = String::with_capacity(fragment.len() + 1);
+        url.push('#');
+
+        for c in fragment.chars() {
+            if c.is_ascii_control() || matches!(c, ' ' | '"' | '<' | '>' | '`') {
+                // Writing to a `String` cannot fail.
+                let _ = write!(url, "%{:02X}", u32::from(c));
+            } else {
+                url.push(c);
+            }
+        }
+
+        Self(url)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The URL escaped for use as an attribute value.
+    #[must_use]
+    pub fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// The scheme of the URL, if it is one which may run scripts.
+///
+/// Browsers ignore leading spaces and control characters, and tabs and newlines anywhere,
+/// so `" java\tscript:"` must be caught too.
+fn blocked_scheme(url: &str) -> Option<String> {
+    let url = url
+        .trim_start_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect::<String>();
+
+    let (scheme, rest) = url.split_once(':')?;
+
+    let is_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !is_scheme {
+        // E.g. `./a:b`, a relative reference.
+        return None;
+    }
+
+    let scheme = scheme.to_ascii_lowercase();
+
+    match scheme.as_str() {
+        "javascript" | "vbscript" => Some(scheme),
+        "data" => {
+            let mime = rest
+                .split([';', ','])
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase();
+
+            (!DATA_IMAGE_TYPES.contains(&mime.as_str())).then_some(scheme)
+        }
+        _ => None,
+    }
+}
+
+impl fmt::Display for SafeUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for SafeUrl {
+    fn from(url: &str) -> Self {
+        Self::sanitize(url)
+    }
+}
+
+impl From<String> for SafeUrl {
+    fn from(url: String) -> Self {
+        Self::sanitize(&url)
+    }
+}
+
+impl From<&String> for SafeUrl {
+    fn from(url: &String) -> Self {
+        Self::sanitize(url)
+    }
+}
+
+impl From<url::Url> for SafeUrl {
+    fn from(url: url::Url) -> Self {
+        Self::sanitize(url.as_str())
+    }
+}
+
+impl From<&url::Url> for SafeUrl {
+    fn from(url: &url::Url) -> Self {
+        Self::sanitize(url.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        tags::{form::Method, Form, Img, A},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn relative_references() {
+        for url in [
+            "/hn",
+            "../style.css",
+            "?p=2",
+            "#top",
+            "./a:b",
+            "item?id=1&p=2",
+        ] {
+            assert_eq!(url, SafeUrl::new(url).unwrap().as_str());
+        }
+    }
+
+    #[test]
+    fn blocked_schemes() {
+        for url in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            " \u{1}java\tscript:alert(1)",
+            "vbscript:msgbox(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "data:image/svg+xml;base64,PHN2Zz4=",
+        ] {
+            assert!(SafeUrl::new(url).is_err(), "{url}");
+            assert_eq!(BLOCKED, SafeUrl::from(url).as_str());
+        }
+
+        assert_eq!(
+            "data",
+            SafeUrl::new("DATA:text/html,hi").unwrap_err().scheme()
+        );
+    }
+
+    #[test]
+    fn allowed_schemes() {
+        for url in [
+            "https://news.ycombinator.com",
+            "mailto:pg@example.com",
+            "data:image/png;base64,iVBORw0KGgo=",
+            "data:IMAGE/JPEG;base64,/9j/4AAQ",
+        ] {
+            assert!(SafeUrl::new(url).is_ok(), "{url}");
+        }
+    }
+
+    #[test]
+    fn fragment() {
+        assert_eq!("#comments", SafeUrl::fragment("comments").as_str());
+        assert_eq!("#a%20%22b%22", SafeUrl::fragment("a \"b\"").as_str());
+    }
+
+    #[test]
+    fn render_neutralised_and_escaped() {
+        let url = url::Url::parse("https://example.com/search?q=a&lang=en").unwrap();
+
+        assert_eq!(
+            r#"<a href="https://example.com/search?q=a&amp;lang=en"></a>"#,
+            o(A::href(url)).render_string().unwrap()
+        );
+        assert_eq!(
+            r#"<a href="about:invalid#blocked"></a>"#,
+            o(A::href("javascript:alert(1)")).render_string().unwrap()
+        );
+        assert!(o(Img::new("data:text/html,hi"))
+            .render_string()
+            .unwrap()
+            .starts_with(r#"<img src="about:invalid#blocked">"#));
+        assert_eq!(
+            r#"<form method="post" action="about:invalid#blocked"></form>"#,
+            o(Form::new(Method::Post, "javascript:void(0)"))
+                .render_string()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn render_trusted() {
+        assert_eq!(
+            r#"<a href="javascript:history.back()"></a>"#,
+            o(A::href(SafeUrl::trusted("javascript:history.back()")))
+                .render_string()
+                .unwrap()
+        );
+    }
+}
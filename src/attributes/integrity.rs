@@ -0,0 +1,300 @@
+use std::{fmt, fs, io, path::Path, str::FromStr};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::global_attributes::Attribute;
+
+/// A hash algorithm supported by Subresource Integrity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Algorithm {
+    Sha256,
+    /// The usual choice, and what CDNs tend to publish.
+    #[default]
+    Sha384,
+    Sha512,
+}
+
+impl Algorithm {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha384 => "sha384",
+            Self::Sha512 => "sha512",
+        }
+    }
+
+    fn digest(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Sha256 => Sha256::digest(bytes).to_vec(),
+            Self::Sha384 => Sha384::digest(bytes).to_vec(),
+            Self::Sha512 => Sha512::digest(bytes).to_vec(),
+        }
+    }
+
+    /// Length of the base64 encoded digest.
+    const fn encoded_len(self) -> usize {
+        match self {
+            Self::Sha256 => 44,
+            Self::Sha384 => 64,
+            Self::Sha512 => 88,
+        }
+    }
+}
+
+/// An integrity value was not a list of `<algorithm>-<base64 digest>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIntegrity(String);
+
+impl fmt::Display for InvalidIntegrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid integrity `{}`: expected e.g. `sha384-<base64 digest>`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidIntegrity {}
+
+/// Subresource Integrity, i.e. the `integrity` attribute.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity).
+///
+/// Either parse the value published by a CDN,
+/// or compute it from the file being served, e.g. [`Integrity::of_file`].
+/// Several hashes may be combined, in which case browsers use the strongest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Integrity(Vec<(Algorithm, String)>);
+
+impl Integrity {
+    /// Parse an integrity value such as `sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC`.
+    ///
+    /// # Errors
+    ///
+    /// If any whitespace separated hash is not an algorithm,
+    /// a dash and a base64 digest of the algorithm's length.
+    pub fn new(integrity: &str) -> Result<Self, InvalidIntegrity> {
+        integrity.parse()
+    }
+
+    /// Hash the given bytes.
+    #[must_use]
+    pub fn of_bytes(algorithm: Algorithm, bytes: &[u8]) -> Self {
+        Self(vec![(algorithm, STANDARD.encode(algorithm.digest(bytes)))])
+    }
+
+    /// Hash the contents of the given file, e.g. a vendored script.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read.
+    pub fn of_file<P>(algorithm: Algorithm, path: P) -> Result<Self, io::Error>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::of_bytes(algorithm, &fs::read(path)?))
+    }
+
+    /// Accept either this or the other integrity, e.g. during an algorithm migration.
+    #[must_use]
+    pub fn and(mut self, other: Self) -> Self {
+        for hash in other.0 {
+            if !self.0.contains(&hash) {
+                self.0.push(hash);
+            }
+        }
+        self
+    }
+}
+
+impl FromStr for Integrity {
+    type Err = InvalidIntegrity;
+
+    fn from_str(integrity: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidIntegrity(integrity.to_string());
+
+        let hashes = integrity
+            .split_whitespace()
+            .map(|hash| {
+                let (algorithm, digest) = hash.split_once('-').ok_or_else(invalid)?;
+                let algorithm = [Algorithm::Sha256, Algorithm::Sha384, Algorithm::Sha512]
+                    .into_iter()
+                    .find(|a| a.as_str() == algorithm)
+                    .ok_or_else(invalid)?;
+
+                // Options after `?` are reserved, but allowed.
+                let digest = digest.split('?').next().unwrap_or_default();
+                let valid =
+                    digest.len() == algorithm.encoded_len() && STANDARD.decode(digest).is_ok();
+
+                if valid {
+                    Ok((algorithm, digest.to_string()))
+                } else {
+                    Err(invalid())
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if hashes.is_empty() {
+            Err(invalid())
+        } else {
+            Ok(Self(hashes))
+        }
+    }
+}
+
+impl fmt::Display for Integrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hashes = self
+            .0
+            .iter()
+            .map(|(algorithm, digest)| format!("{}-{digest}", algorithm.as_str()))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", hashes.join(" "))
+    }
+}
+
+impl Attribute for Integrity {
+    fn name(&self) -> &'static str {
+        "integrity"
+    }
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// How a resource is fetched with respect to CORS.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/crossorigin).
+///
+/// Resources from other origins need this for their [`Integrity`] to be checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossOrigin {
+    /// Without credentials such as cookies, the usual choice for CDNs.
+    Anonymous,
+    UseCredentials,
+}
+
+impl Attribute for CrossOrigin {
+    fn name(&self) -> &'static str {
+        "crossorigin"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Anonymous => "anonymous",
+            Self::UseCredentials => "use-credentials",
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        tags::{Link, Script},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    // From the SRI specification's examples.
+    const ALERT: &str = "alert('Hello, world.');";
+
+    #[test]
+    fn hash_bytes() {
+        assert_eq!(
+            "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng=",
+            Integrity::of_bytes(Algorithm::Sha256, ALERT.as_bytes()).to_string()
+        );
+        assert_eq!(
+            "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO",
+            Integrity::of_bytes(Algorithm::Sha384, ALERT.as_bytes()).to_string()
+        );
+        assert_eq!(
+            "sha512-Q2bFTOhEALkN8hOms2FKTDLy7eugP2zFZ1T8LCvX42Fp3WoNr3bjZSAHeOsHrbV1Fu9/A0EzCinRE7Af1ofPrw==",
+            Integrity::of_bytes(Algorithm::Sha512, ALERT.as_bytes()).to_string()
+        );
+    }
+
+    #[test]
+    fn hash_file() {
+        let path = std::env::temp_dir().join("html-strong-integrity-test.js");
+        std::fs::write(&path, ALERT).unwrap();
+
+        assert_eq!(
+            Integrity::of_bytes(Algorithm::Sha384, ALERT.as_bytes()),
+            Integrity::of_file(Algorithm::Sha384, &path).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let integrity = "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng= \
+             sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO";
+
+        assert_eq!(
+            integrity.split_whitespace().collect::<Vec<_>>().join(" "),
+            Integrity::new(integrity).unwrap().to_string()
+        );
+        assert!(Integrity::new("").is_err());
+        assert!(Integrity::new("md5-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng=").is_err());
+        assert!(Integrity::new("sha384-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng=").is_err());
+    }
+
+    #[test]
+    fn render_cdn_script_and_stylesheet() {
+        let integrity = Integrity::new(
+            "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO",
+        )
+        .unwrap();
+
+        let result = o(Script::src("https://cdn.example.com/highlight.min.js")
+            .integrity(integrity.clone())
+            .crossorigin(CrossOrigin::Anonymous))
+        .render_string()
+        .unwrap();
+
+        assert_eq!(
+            format!(
+                r#"<script src="https://cdn.example.com/highlight.min.js" integrity="{integrity}" crossorigin="anonymous"></script>"#
+            ),
+            result
+        );
+
+        let result = o(
+            Link::stylesheet(mime::TEXT_CSS, "https://cdn.example.com/bootstrap.css")
+                .integrity(integrity.clone())
+                .unwrap()
+                .crossorigin(CrossOrigin::Anonymous),
+        )
+        .render_string()
+        .unwrap();
+
+        assert_eq!(
+            format!(
+                r#"<link rel="stylesheet" type="text/css" href="https://cdn.example.com/bootstrap.css" integrity="{integrity}" crossorigin="anonymous">"#
+            ),
+            result
+        );
+    }
+
+    #[test]
+    fn render_vendored() {
+        let path = std::env::temp_dir().join("html-strong-vendored-test.js");
+        std::fs::write(&path, ALERT).unwrap();
+
+        let result = o(Script::vendored(&path, "/static/hello.js").unwrap())
+            .render_string()
+            .unwrap();
+
+        assert_eq!(
+            r#"<script src="/static/hello.js" integrity="sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"></script>"#,
+            result
+        );
+    }
+}
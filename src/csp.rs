@@ -0,0 +1,501 @@
+use std::{fmt, io};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256};
+
+use crate::{document_tree::Node, global_attributes::Nonce, tags::Meta};
+
+/// A policy directive, see [MDN](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy#directives).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Directive {
+    DefaultSrc,
+    ScriptSrc,
+    StyleSrc,
+    /// Inline event handler attributes, e.g. `onclick`.
+    ScriptSrcAttr,
+    /// Inline `style` attributes.
+    StyleSrcAttr,
+    ImgSrc,
+    FontSrc,
+    ConnectSrc,
+    MediaSrc,
+    ObjectSrc,
+    FrameSrc,
+    ChildSrc,
+    WorkerSrc,
+    ManifestSrc,
+    BaseUri,
+    FormAction,
+    FrameAncestors,
+    ReportUri,
+    ReportTo,
+    UpgradeInsecureRequests,
+}
+
+impl Directive {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::DefaultSrc => "default-src",
+            Self::ScriptSrc => "script-src",
+            Self::StyleSrc => "style-src",
+            Self::ScriptSrcAttr => "script-src-attr",
+            Self::StyleSrcAttr => "style-src-attr",
+            Self::ImgSrc => "img-src",
+            Self::FontSrc => "font-src",
+            Self::ConnectSrc => "connect-src",
+            Self::MediaSrc => "media-src",
+            Self::ObjectSrc => "object-src",
+            Self::FrameSrc => "frame-src",
+            Self::ChildSrc => "child-src",
+            Self::WorkerSrc => "worker-src",
+            Self::ManifestSrc => "manifest-src",
+            Self::BaseUri => "base-uri",
+            Self::FormAction => "form-action",
+            Self::FrameAncestors => "frame-ancestors",
+            Self::ReportUri => "report-uri",
+            Self::ReportTo => "report-to",
+            Self::UpgradeInsecureRequests => "upgrade-insecure-requests",
+        }
+    }
+
+    /// Browsers ignore these when the policy is delivered via `<meta>`.
+    const fn header_only(self) -> bool {
+        matches!(
+            self,
+            Self::FrameAncestors | Self::ReportUri | Self::ReportTo
+        )
+    }
+}
+
+/// A source expression, e.g. `'self'` or `https://example.com`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// `'self'`, the document's own origin.
+    SelfOrigin,
+    /// `'none'`, nothing at all. Dropped when another source is added.
+    None,
+    /// `'unsafe-inline'`, ignored by browsers when a nonce or hash is present.
+    UnsafeInline,
+    /// `'unsafe-eval'`.
+    UnsafeEval,
+    /// `'unsafe-hashes'`.
+    UnsafeHashes,
+    /// `'strict-dynamic'`, trust scripts loaded by already trusted scripts.
+    StrictDynamic,
+    /// `'wasm-unsafe-eval'`.
+    WasmUnsafeEval,
+    /// `'nonce-...'`.
+    Nonce(String),
+    /// `'sha256-...'`, the base64 encoded digest.
+    Sha256(String),
+    /// A host such as `cdn.example.com` or `https://*.example.com`,
+    /// or any other source not covered by the variants above.
+    Host(String),
+    /// A scheme such as `https:` or `data:`.
+    Scheme(String),
+}
+
+impl Source {
+    #[must_use]
+    pub fn host(host: &str) -> Self {
+        Self::Host(host.to_string())
+    }
+
+    /// A scheme, with or without the trailing colon.
+    #[must_use]
+    pub fn scheme(scheme: &str) -> Self {
+        Self::Scheme(scheme.trim_end_matches(':').to_string())
+    }
+
+    /// The hash of some inline content, e.g. the text of a `<script>`.
+    #[must_use]
+    pub fn sha256(content: &str) -> Self {
+        Self::Sha256(STANDARD.encode(Sha256::digest(content.as_bytes())))
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SelfOrigin => write!(f, "'self'"),
+            Self::None => write!(f, "'none'"),
+            Self::UnsafeInline => write!(f, "'unsafe-inline'"),
+            Self::UnsafeEval => write!(f, "'unsafe-eval'"),
+            Self::UnsafeHashes => write!(f, "'unsafe-hashes'"),
+            Self::StrictDynamic => write!(f, "'strict-dynamic'"),
+            Self::WasmUnsafeEval => write!(f, "'wasm-unsafe-eval'"),
+            Self::Nonce(nonce) => write!(f, "'nonce-{nonce}'"),
+            Self::Sha256(digest) => write!(f, "'sha256-{digest}'"),
+            Self::Host(host) => write!(f, "{host}"),
+            Self::Scheme(scheme) => write!(f, "{scheme}:"),
+        }
+    }
+}
+
+/// How inline `<script>` and `<style>` elements are allowed by the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Inline {
+    /// Generate a nonce per render and set it on every `<script>` and `<style>`.
+    #[default]
+    Nonce,
+    /// Allow each inline `<script>` and `<style>` by the hash of its content.
+    /// Suitable for cached responses, since the policy only changes with the content.
+    Hash,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Directives(Vec<(Directive, Vec<Source>)>);
+
+impl Directives {
+    fn get(&self, directive: Directive) -> Option<&Vec<Source>> {
+        self.0
+            .iter()
+            .find_map(|(d, sources)| (*d == directive).then_some(sources))
+    }
+
+    fn entry(&mut self, directive: Directive) -> &mut Vec<Source> {
+        let index = if let Some(index) = self.0.iter().position(|(d, _)| *d == directive) {
+            index
+        } else {
+            // Scripts and styles fall back to `default-src` when not given,
+            // so start from there to not loosen nor tighten the policy.
+            let sources = match directive {
+                Directive::ScriptSrc | Directive::StyleSrc => {
+                    self.get(Directive::DefaultSrc).cloned().unwrap_or_default()
+                }
+                _ => vec![],
+            };
+            self.0.push((directive, sources));
+            self.0.len() - 1
+        };
+
+        &mut self.0[index].1
+    }
+
+    fn add(&mut self, directive: Directive, source: Source) {
+        let sources = self.entry(directive);
+
+        if source != Source::None {
+            sources.retain(|s| *s != Source::None);
+        }
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>, meta: bool) -> fmt::Result {
+        let mut directives = self
+            .0
+            .iter()
+            .filter(|(directive, _)| !(meta && directive.header_only()));
+
+        if let Some(first) = directives.next() {
+            render_directive(f, first)?;
+        }
+        for directive in directives {
+            write!(f, "; ")?;
+            render_directive(f, directive)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn render_directive(
+    f: &mut fmt::Formatter<'_>,
+    (directive, sources): &(Directive, Vec<Source>),
+) -> fmt::Result {
+    write!(f, "{}", directive.as_str())?;
+
+    for source in sources {
+        write!(f, " {source}")?;
+    }
+
+    Ok(())
+}
+
+/// A Content Security Policy, see [MDN](https://developer.mozilla.org/en-US/docs/Web/HTTP/CSP).
+///
+/// The policy is applied to a rendered document tree with [`Csp::apply`],
+/// which allows the document's inline `<script>` and `<style>` elements
+/// by either a fresh nonce or their hashes, see [`Inline`].
+/// The resulting [`Policy`] is then sent as a response header,
+/// or embedded in the document via `<meta http-equiv>`.
+///
+/// Inline event handler attributes such as `onclick` are not covered,
+/// use [`crate::global_attributes::Listener`] instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Csp {
+    directives: Directives,
+    inline: Inline,
+    meta: bool,
+}
+
+impl Csp {
+    /// An empty policy, which allows everything until directives are added.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only allow resources from the document's own origin,
+    /// and no plugins nor changes of the base URL.
+    #[must_use]
+    pub fn strict() -> Self {
+        Self::new()
+            .directive(Directive::DefaultSrc, [Source::SelfOrigin])
+            .directive(Directive::ObjectSrc, [Source::None])
+            .directive(Directive::BaseUri, [Source::None])
+    }
+
+    /// Add sources to a directive.
+    /// A directive without sources, e.g. `upgrade-insecure-requests`, is added as is.
+    #[must_use]
+    pub fn directive<I>(mut self, directive: Directive, sources: I) -> Self
+    where
+        I: IntoIterator<Item = Source>,
+    {
+        self.directives.entry(directive);
+
+        for source in sources {
+            self.directives.add(directive, source);
+        }
+        self
+    }
+
+    /// Choose how inline scripts and styles are allowed. Defaults to [`Inline::Nonce`].
+    #[must_use]
+    pub const fn with_inline(mut self, inline: Inline) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Also insert the policy at the start of the document's `<head>` when applied,
+    /// see [`Policy::to_meta`].
+    #[must_use]
+    pub const fn with_meta(mut self) -> Self {
+        self.meta = true;
+        self
+    }
+
+    /// Allow the document's inline scripts and styles, and return the resulting policy.
+    ///
+    /// Call this for every response when using [`Inline::Nonce`],
+    /// since a nonce must never be reused.
+    /// Any nonce already set on a `<script>` or `<style>` is replaced.
+    ///
+    /// # Errors
+    ///
+    /// If rendering the content of a `<script>` or `<style>` fails, see [`Node::inner_html`].
+    pub fn apply(&self, document: &mut Node) -> Result<Policy, io::Error> {
+        let nonce = match self.inline {
+            Inline::Nonce => Some(Nonce::generate()),
+            Inline::Hash => None,
+        };
+
+        let mut directives = self.directives.clone();
+        let mut result = Ok(());
+
+        document.visit_mut(&mut |node| {
+            let directive = match node.tag_name() {
+                Some("script") => Directive::ScriptSrc,
+                Some("style") => Directive::StyleSrc,
+                _ => return,
+            };
+
+            if let Some(nonce) = &nonce {
+                node.set_attr(nonce.clone());
+                directives.add(directive, Source::Nonce(nonce.as_str().to_string()));
+            } else if !node.has_attr("src") {
+                match node.inner_html() {
+                    Ok(content) => directives.add(directive, Source::sha256(&content)),
+                    Err(e) => result = Err(e),
+                }
+            }
+        });
+        result?;
+
+        let policy = Policy { directives, nonce };
+
+        if self.meta {
+            let mut inserted = false;
+
+            document.visit_mut(&mut |node| {
+                if !inserted && node.tag_name() == Some("head") {
+                    node.push_kid_front(policy.to_meta());
+                    inserted = true;
+                }
+            });
+        }
+
+        Ok(policy)
+    }
+}
+
+/// A policy applied to a document, see [`Csp::apply`].
+///
+/// Renders as the value of the `Content-Security-Policy` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    directives: Directives,
+    nonce: Option<Nonce>,
+}
+
+impl Policy {
+    /// The name of the response header carrying the policy.
+    pub const HEADER: &'static str = "Content-Security-Policy";
+
+    /// The nonce generated for this policy, if using [`Inline::Nonce`].
+    /// Use it for any elements added to the document after applying the policy.
+    #[must_use]
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_ref().map(Nonce::as_str)
+    }
+
+    /// The value of the response header.
+    #[must_use]
+    pub fn header_value(&self) -> String {
+        self.to_string()
+    }
+
+    /// The policy as a `<meta http-equiv>` tag.
+    ///
+    /// Directives which browsers ignore in `<meta>`,
+    /// e.g. `frame-ancestors` and reporting, are left out.
+    #[must_use]
+    pub fn to_meta(&self) -> Meta {
+        Meta::content_security_policy(&MetaPolicy(self).to_string())
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.directives.render(f, false)
+    }
+}
+
+struct MetaPolicy<'a>(&'a Policy);
+
+impl fmt::Display for MetaPolicy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.directives.render(f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        global_attributes::Event,
+        science_lab::NodeExt,
+        tags::{Body, Button, Head, Script, Style},
+        template::HtmlDocumentBuilder,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn document() -> Node {
+        HtmlDocumentBuilder::new()
+            .with_head(
+                o(Head)
+                    .kid(Script::src("/static/app.js"))
+                    .kid(o(Style).add_text("p{color:red}")),
+            )
+            .with_body(
+                Body.kid(Script::new().text("alert(1)"))
+                    .kid(Button::new().listen(Event::Click, "go()")),
+            )
+            .build()
+    }
+
+    #[test]
+    fn nonce_stamped_on_scripts_and_styles() {
+        let mut document = document();
+
+        let policy = Csp::strict().apply(&mut document).unwrap();
+        let nonce = policy.nonce().unwrap();
+        let result = document.render_string().unwrap();
+
+        // Two inline scripts, one external script and one style.
+        assert_eq!(4, result.matches(&format!(r#"nonce="{nonce}""#)).count());
+        assert_eq!(
+            format!(
+                "default-src 'self'; object-src 'none'; base-uri 'none'; \
+                 script-src 'self' 'nonce-{nonce}'; style-src 'self' 'nonce-{nonce}'"
+            ),
+            policy.header_value()
+        );
+    }
+
+    #[test]
+    fn nonce_fresh_per_apply() {
+        let csp = Csp::strict();
+
+        let first = csp.apply(&mut document()).unwrap();
+        let second = csp.apply(&mut document()).unwrap();
+
+        assert_ne!(first.nonce(), second.nonce());
+        assert_eq!(24, first.nonce().unwrap().len());
+    }
+
+    #[test]
+    fn hashes_of_inline_content() {
+        let mut document = o(Body)
+            .kid(Script::new().text("alert(1)"))
+            .kid(Script::src("/static/app.js"));
+
+        let policy = Csp::new()
+            .directive(Directive::ScriptSrc, [Source::None])
+            .with_inline(Inline::Hash)
+            .apply(&mut document)
+            .unwrap();
+
+        assert_eq!(None, policy.nonce());
+        assert_eq!(
+            "script-src 'sha256-bhHHL3z2vDgxUt0W3dWQOrprscmda2Y5pLsLg4GF+pI='",
+            policy.to_string()
+        );
+        assert!(!document.render_string().unwrap().contains("nonce"));
+    }
+
+    #[test]
+    fn meta_leaves_out_header_only_directives() {
+        let mut document = HtmlDocumentBuilder::new().with_head(o(Head)).build();
+
+        let policy = Csp::strict()
+            .directive(Directive::FrameAncestors, [Source::None])
+            .directive(Directive::UpgradeInsecureRequests, [])
+            .with_meta()
+            .apply(&mut document)
+            .unwrap();
+
+        assert!(policy.header_value().contains("frame-ancestors 'none'"));
+        assert!(document.render_string().unwrap().contains(
+            r#"<head><meta http-equiv="Content-Security-Policy" content="default-src 'self'; object-src 'none'; base-uri 'none'; upgrade-insecure-requests"></head>"#
+        ));
+    }
+
+    #[test]
+    fn sources() {
+        let csp = Csp::new()
+            .directive(Directive::ImgSrc, [Source::None])
+            .directive(
+                Directive::ImgSrc,
+                [
+                    Source::SelfOrigin,
+                    Source::scheme("data:"),
+                    Source::host("https://*.example.com"),
+                    Source::SelfOrigin,
+                ],
+            );
+
+        let policy = csp.apply(&mut o(Body)).unwrap();
+
+        assert_eq!(
+            "img-src 'self' data: https://*.example.com",
+            policy.header_value()
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod declarations;
+pub mod property;
+pub mod scoped;
+pub mod selector;
+pub mod stylesheet;
+pub mod value;
+
+pub use declarations::Css;
+pub use property::Property;
+pub use scoped::{Scope, ScopedStyle};
+pub use selector::{PseudoClass, PseudoElement, Selector};
+pub use stylesheet::{Format, Keyframes, Rule, Stylesheet};
+pub use value::{ch, em, fr, percent, pt, px, rem, vh, vw, Color, Keyword, Length, Unit, Value};
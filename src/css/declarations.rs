@@ -0,0 +1,218 @@
+use std::fmt;
+
+use super::{Property, Value};
+
+/// A block of declarations, e.g. the contents of a `style` attribute.
+///
+/// Each property appears at most once: setting a property again replaces its value
+/// but keeps its position, so rendering is deterministic.
+///
+/// ```
+/// use html_strong::css::{px, Color, Css, Property};
+///
+/// let css = Css::new()
+///     .set(Property::LineHeight, px(12))
+///     .set(Property::Color, Color::Rgb(255, 102, 0))
+///     .set(Property::LineHeight, px(14));
+///
+/// assert_eq!("line-height:14px;color:#ff6600", css.to_string());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Css {
+    declarations: Vec<(Property, Value)>,
+}
+
+/// Split on `separator`, but not within parentheses or quotes,
+/// e.g. `url(data:image/png;base64,...)`.
+fn split_outside(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0_usize;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, c) if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+
+    parts
+}
+
+impl Css {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            declarations: vec![],
+        }
+    }
+
+    /// Parse declarations such as `line-height:12pt; height:10px;`.
+    ///
+    /// Values are kept as [`Value::Raw`].
+    /// Declarations without a `:` are skipped.
+    #[must_use]
+    pub fn parse(style: &str) -> Self {
+        let mut css = Self::new();
+
+        for declaration in split_outside(style, ';') {
+            if let Some((property, value)) = declaration.split_once(':') {
+                let (property, value) = (property.trim(), value.trim());
+
+                if !property.is_empty() && !value.is_empty() {
+                    css.insert(Property::from_name(property), Value::Raw(value.to_string()));
+                }
+            }
+        }
+
+        css
+    }
+
+    /// Set a declaration, replacing any previous value for the property.
+    #[must_use]
+    pub fn set<V>(mut self, property: Property, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.insert(property, value);
+        self
+    }
+
+    /// Set a declaration, replacing any previous value for the property.
+    pub fn insert<V>(&mut self, property: Property, value: V)
+    where
+        V: Into<Value>,
+    {
+        let value = value.into();
+
+        match self.declarations.iter_mut().find(|(p, _)| *p == property) {
+            Some((_, previous)) => *previous = value,
+            None => self.declarations.push((property, value)),
+        }
+    }
+
+    /// Set all declarations from `other`, where `other` wins on conflicts.
+    pub fn merge(&mut self, other: Self) {
+        for (property, value) in other.declarations {
+            self.insert(property, value);
+        }
+    }
+
+    /// Remove a declaration.
+    pub fn remove(&mut self, property: &Property) -> Option<Value> {
+        let index = self.declarations.iter().position(|(p, _)| p == property)?;
+
+        Some(self.declarations.remove(index).1)
+    }
+
+    #[must_use]
+    pub fn get(&self, property: &Property) -> Option<&Value> {
+        self.declarations
+            .iter()
+            .find_map(|(p, value)| (p == property).then_some(value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Property, &Value)> {
+        self.declarations.iter().map(|(p, v)| (p, v))
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.declarations.len()
+    }
+}
+
+impl From<&str> for Css {
+    fn from(style: &str) -> Self {
+        Self::parse(style)
+    }
+}
+
+impl fmt::Display for Css {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (property, value)) in self.declarations.iter().enumerate() {
+            if index > 0 {
+                write!(f, ";")?;
+            }
+            write!(f, "{property}:{value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css::{percent, px, rem, Color, Keyword};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn last_wins_keeps_position() {
+        let css = Css::new()
+            .set(Property::Width, percent(85))
+            .set(Property::BackgroundColor, Color::hex("#f6f6ef").unwrap())
+            .set(Property::Width, px(300));
+
+        assert_eq!("width:300px;background-color:#f6f6ef", css.to_string());
+    }
+
+    #[test]
+    fn parse_legacy_style() {
+        let css = Css::parse("line-height:12pt; HEIGHT:10px;;  bogus ; --accent: #f60");
+
+        assert_eq!(
+            "line-height:12pt;height:10px;--accent:#f60",
+            css.to_string()
+        );
+        assert_eq!(Some(&Value::Raw("10px".into())), css.get(&Property::Height));
+    }
+
+    #[test]
+    fn parse_keeps_nested_separators() {
+        let css = Css::parse("background-image:url(data:image/png;base64,AAAA);content:'a;b'");
+
+        assert_eq!(2, css.len());
+        assert_eq!(
+            "background-image:url(data:image/png;base64,AAAA);content:'a;b'",
+            css.to_string()
+        );
+    }
+
+    #[test]
+    fn merge_and_lists() {
+        let mut css = Css::parse("border:none;padding:2px");
+        css.merge(
+            Css::new()
+                .set(
+                    Property::Border,
+                    Value::list([
+                        Value::from(px(1)),
+                        Keyword::Solid.into(),
+                        Color::White.into(),
+                    ]),
+                )
+                .set(Property::custom("gap"), rem(1.5)),
+        );
+
+        assert_eq!(
+            "border:1px solid white;padding:2px;--gap:1.5rem",
+            css.to_string()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt;
+
+macro_rules! properties {
+    ( $( $variant:ident => $name:literal ),* $(,)? ) => {
+        /// A CSS property.
+        ///
+        /// Custom properties (`--*`) use [`Property::Custom`],
+        /// and properties without a variant yet use [`Property::Other`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum Property {
+            $( $variant, )*
+            /// A custom property including the leading `--`, see [`Property::custom`].
+            Custom(String),
+            Other(String),
+        }
+
+        impl Property {
+            /// The property's name, e.g. `line-height`.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( Self::$variant => $name, )*
+                    Self::Custom(name) | Self::Other(name) => name,
+                }
+            }
+
+            /// Look up a property by name.
+            /// Names are case-insensitive, except for custom properties.
+            #[must_use]
+            pub fn from_name(name: &str) -> Self {
+                let name = name.trim();
+
+                if name.starts_with("--") {
+                    return Self::Custom(name.to_string());
+                }
+
+                let name = name.to_ascii_lowercase();
+                match name.as_str() {
+                    $( $name => Self::$variant, )*
+                    _ => Self::Other(name),
+                }
+            }
+        }
+    };
+}
+
+properties![
+    AlignItems => "align-items",
+    Animation => "animation",
+    Background => "background",
+    BackgroundColor => "background-color",
+    BackgroundImage => "background-image",
+    Border => "border",
+    BorderBottom => "border-bottom",
+    BorderCollapse => "border-collapse",
+    BorderColor => "border-color",
+    BorderRadius => "border-radius",
+    BorderSpacing => "border-spacing",
+    BorderTop => "border-top",
+    Bottom => "bottom",
+    BoxShadow => "box-shadow",
+    BoxSizing => "box-sizing",
+    Color => "color",
+    Content => "content",
+    Cursor => "cursor",
+    Display => "display",
+    Filter => "filter",
+    Flex => "flex",
+    FlexDirection => "flex-direction",
+    FlexWrap => "flex-wrap",
+    Float => "float",
+    FontDisplay => "font-display",
+    FontFamily => "font-family",
+    FontSize => "font-size",
+    FontStyle => "font-style",
+    FontWeight => "font-weight",
+    Gap => "gap",
+    GridTemplateColumns => "grid-template-columns",
+    Height => "height",
+    JustifyContent => "justify-content",
+    Left => "left",
+    LetterSpacing => "letter-spacing",
+    LineHeight => "line-height",
+    ListStyle => "list-style",
+    Margin => "margin",
+    MarginBottom => "margin-bottom",
+    MarginLeft => "margin-left",
+    MarginRight => "margin-right",
+    MarginTop => "margin-top",
+    MaxHeight => "max-height",
+    MaxWidth => "max-width",
+    MinHeight => "min-height",
+    MinWidth => "min-width",
+    ObjectFit => "object-fit",
+    Opacity => "opacity",
+    Outline => "outline",
+    Overflow => "overflow",
+    Padding => "padding",
+    PaddingBottom => "padding-bottom",
+    PaddingLeft => "padding-left",
+    PaddingRight => "padding-right",
+    PaddingTop => "padding-top",
+    Position => "position",
+    Right => "right",
+    Src => "src",
+    TextAlign => "text-align",
+    TextDecoration => "text-decoration",
+    TextOverflow => "text-overflow",
+    TextTransform => "text-transform",
+    Top => "top",
+    Transform => "transform",
+    Transition => "transition",
+    VerticalAlign => "vertical-align",
+    Visibility => "visibility",
+    WhiteSpace => "white-space",
+    Width => "width",
+    WordBreak => "word-break",
+    ZIndex => "z-index",
+];
+
+impl Property {
+    /// A custom property, e.g. `Property::custom("accent")` is `--accent`.
+    #[must_use]
+    pub fn custom(name: &str) -> Self {
+        if name.starts_with("--") {
+            Self::Custom(name.to_string())
+        } else {
+            Self::Custom(format!("--{name}"))
+        }
+    }
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
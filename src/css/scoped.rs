@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use crate::hash::fnv1a;
+
+use super::{Format, Selector, Stylesheet};
+
+/// Hands out class names that are unique to a component.
+///
+/// The hash is derived from the component's name, and by [`ScopedStyle::new`] also from its styles,
+/// so the same component gets the same class names across renders and builds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scope {
+    component: String,
+    hash: String,
+}
+
+impl Scope {
+    #[must_use]
+    pub fn new(component: &str) -> Self {
+        Self::hashed(component, component)
+    }
+
+    fn hashed(component: &str, content: &str) -> Self {
+        Self {
+            component: component.into(),
+            hash: format!("{:08x}", fnv1a(content)),
+        }
+    }
+
+    /// The hashed version of a class name local to the component, e.g. `card_1a2b3c4d`.
+    #[must_use]
+    pub fn class(&self, class: &str) -> String {
+        format!("{class}_{}", self.hash)
+    }
+
+    /// A selector for a class local to the component.
+    #[must_use]
+    pub fn selector(&self, class: &str) -> Selector {
+        Selector::class(&self.class(class))
+    }
+
+    #[must_use]
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    scope: Scope,
+    stylesheet: Stylesheet,
+}
+
+/// A component's styles, similar to CSS Modules.
+///
+/// Classes are added to nodes via [`crate::document_tree::Node::add_scoped_class`],
+/// which also remembers that the node uses these styles.
+/// When building a document via [`crate::template::HtmlDocumentBuilder`],
+/// the styles of every component used on the page are collected into the head.
+///
+/// Cloning is cheap, the stylesheet is shared.
+///
+/// ```
+/// use html_strong::css::{px, Property, Rule, ScopedStyle, Stylesheet};
+/// use html_strong::{science_lab::NodeExt, tags::Div};
+///
+/// let card = ScopedStyle::new("Card", |scope| {
+///     Stylesheet::new().rule(Rule::new(scope.selector("card")).set(Property::Padding, px(8)))
+/// });
+///
+/// let node = Div.scoped_class(&card, "card");
+/// assert_eq!(
+///     format!(r#"<div class="{}"></div>"#, card.class("card")),
+///     node.render_string().unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScopedStyle(Arc<Inner>);
+
+impl ScopedStyle {
+    /// Declare the styles of a component.
+    ///
+    /// The closure builds the stylesheet, using the given [`Scope`] for any class selectors.
+    ///
+    /// The class names hash both the name and the styles,
+    /// so components sharing a name but not their styles get different class names.
+    /// To do so the closure is called twice: once to render the styles, and once with the final scope.
+    pub fn new<F>(component: &str, styles: F) -> Self
+    where
+        F: Fn(&Scope) -> Stylesheet,
+    {
+        let css = styles(&Scope::new(component)).render(Format::Minified);
+        let scope = Scope::hashed(component, &format!("{component}\n{css}"));
+        let stylesheet = styles(&scope);
+
+        Self(Arc::new(Inner { scope, stylesheet }))
+    }
+
+    /// See [`Scope::class`].
+    #[must_use]
+    pub fn class(&self, class: &str) -> String {
+        self.0.scope.class(class)
+    }
+
+    #[must_use]
+    pub fn scope(&self) -> &Scope {
+        &self.0.scope
+    }
+
+    #[must_use]
+    pub fn stylesheet(&self) -> &Stylesheet {
+        &self.0.stylesheet
+    }
+}
+
+impl PartialEq for ScopedStyle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+            || (self.0.scope == other.0.scope && self.0.stylesheet == other.0.stylesheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css::{Color, Property, Rule};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn hashes_are_stable_and_distinct() {
+        assert_eq!("card_0855387f", Scope::new("Card").class("card"));
+        assert_ne!(
+            Scope::new("Card").class("x"),
+            Scope::new("Story").class("x")
+        );
+    }
+
+    #[test]
+    fn same_name_different_styles_do_not_collide() {
+        let style = |color: Color| {
+            ScopedStyle::new("Card", move |scope| {
+                Stylesheet::new()
+                    .rule(Rule::new(scope.selector("card")).set(Property::Color, color))
+            })
+        };
+        let (red, blue) = (style(Color::Red), style(Color::Blue));
+
+        assert_ne!(red.class("card"), blue.class("card"));
+        assert_ne!(red, blue);
+        assert_eq!(red, style(Color::Red));
+        assert_eq!(
+            format!(".{}{{color:red}}", red.class("card")),
+            red.stylesheet().render(Format::Minified)
+        );
+    }
+}
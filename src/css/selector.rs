@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::tags::Tag;
+
+/// Pseudo-classes, e.g. `:hover`.
+///
+/// See [`Selector::not`] for `:not()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PseudoClass {
+    Active,
+    Checked,
+    Disabled,
+    FirstChild,
+    Focus,
+    FocusVisible,
+    FocusWithin,
+    Hover,
+    LastChild,
+    Link,
+    Root,
+    Target,
+    Visited,
+    /// The `an+b` formula, e.g. `NthChild(2, 1)` for odd children.
+    NthChild(i32, i32),
+}
+
+impl fmt::Display for PseudoClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active => write!(f, ":active"),
+            Self::Checked => write!(f, ":checked"),
+            Self::Disabled => write!(f, ":disabled"),
+            Self::FirstChild => write!(f, ":first-child"),
+            Self::Focus => write!(f, ":focus"),
+            Self::FocusVisible => write!(f, ":focus-visible"),
+            Self::FocusWithin => write!(f, ":focus-within"),
+            Self::Hover => write!(f, ":hover"),
+            Self::LastChild => write!(f, ":last-child"),
+            Self::Link => write!(f, ":link"),
+            Self::Root => write!(f, ":root"),
+            Self::Target => write!(f, ":target"),
+            Self::Visited => write!(f, ":visited"),
+            Self::NthChild(0, b) => write!(f, ":nth-child({b})"),
+            Self::NthChild(a, 0) => write!(f, ":nth-child({a}n)"),
+            Self::NthChild(a, b) => write!(f, ":nth-child({a}n{b:+})"),
+        }
+    }
+}
+
+/// Pseudo-elements, e.g. `::before`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PseudoElement {
+    After,
+    Before,
+    FirstLetter,
+    FirstLine,
+    Marker,
+    Placeholder,
+    Selection,
+}
+
+impl fmt::Display for PseudoElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::After => "after",
+            Self::Before => "before",
+            Self::FirstLetter => "first-letter",
+            Self::FirstLine => "first-line",
+            Self::Marker => "marker",
+            Self::Placeholder => "placeholder",
+            Self::Selection => "selection",
+        };
+
+        write!(f, "::{name}")
+    }
+}
+
+/// A selector, e.g. `.card:hover` or `td > a`.
+///
+/// Built by starting from a simple selector and chaining compound parts and combinators.
+///
+/// ```
+/// use html_strong::css::{PseudoClass, Selector};
+/// use html_strong::tags::A;
+///
+/// let selector = Selector::class("heading__credits")
+///     .descendant(Selector::tag(&A::new()).pseudo_class(PseudoClass::Hover));
+///
+/// assert_eq!(".heading__credits a:hover", selector.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector(String);
+
+impl Selector {
+    /// Matches any element, i.e. `*`.
+    #[must_use]
+    pub fn universal() -> Self {
+        Self("*".into())
+    }
+
+    /// Matches elements by tag name, e.g. `td`.
+    #[must_use]
+    pub fn tag(tag: &dyn Tag) -> Self {
+        Self(tag.name().into())
+    }
+
+    /// Matches elements having the class.
+    #[must_use]
+    pub fn class(class: &str) -> Self {
+        Self(format!(".{class}"))
+    }
+
+    /// Matches the element with the id.
+    #[must_use]
+    pub fn id(id: &str) -> Self {
+        Self(format!("#{id}"))
+    }
+
+    /// A selector not (yet) expressible with the typed API, used as is.
+    #[must_use]
+    pub fn raw(selector: &str) -> Self {
+        Self(selector.into())
+    }
+
+    /// Also require the class, e.g. `.card.card-1`.
+    #[must_use]
+    pub fn and_class(mut self, class: &str) -> Self {
+        self.0.push('.');
+        self.0.push_str(class);
+        self
+    }
+
+    /// Also require an attribute to be present, e.g. `[open]`.
+    #[must_use]
+    pub fn has_attribute(mut self, name: &str) -> Self {
+        self.0 = format!("{}[{name}]", self.0);
+        self
+    }
+
+    /// Also require an attribute to have a value, e.g. `[type="submit"]`.
+    #[must_use]
+    pub fn attribute_equals(mut self, name: &str, value: &str) -> Self {
+        self.0 = format!("{}[{name}=\"{value}\"]", self.0);
+        self
+    }
+
+    #[must_use]
+    pub fn pseudo_class(mut self, pseudo_class: PseudoClass) -> Self {
+        self.0.push_str(&pseudo_class.to_string());
+        self
+    }
+
+    /// Exclude elements matching the other selector, e.g. `a:not(.visited)`.
+    #[must_use]
+    pub fn not(self, other: Self) -> Self {
+        Self([self.0, ":not(".into(), other.0, ")".into()].concat())
+    }
+
+    #[must_use]
+    pub fn pseudo_element(mut self, pseudo_element: PseudoElement) -> Self {
+        self.0.push_str(&pseudo_element.to_string());
+        self
+    }
+
+    /// Descendant combinator, e.g. `.a .b`.
+    #[must_use]
+    pub fn descendant(self, other: Self) -> Self {
+        Self([self.0, other.0].join(" "))
+    }
+
+    /// Child combinator, e.g. `.a > .b`.
+    #[must_use]
+    pub fn child(self, other: Self) -> Self {
+        Self([self.0, other.0].join(" > "))
+    }
+
+    /// Next-sibling combinator, e.g. `.a + .b`.
+    #[must_use]
+    pub fn next_sibling(self, other: Self) -> Self {
+        Self([self.0, other.0].join(" + "))
+    }
+
+    /// Subsequent-sibling combinator, e.g. `.a ~ .b`.
+    #[must_use]
+    pub fn subsequent_sibling(self, other: Self) -> Self {
+        Self([self.0, other.0].join(" ~ "))
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
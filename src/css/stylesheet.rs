@@ -0,0 +1,448 @@
+use std::{fs, io, path::Path};
+
+use crate::{document_tree::Node, science_lab::NodeExt, tags::Style};
+
+use super::{Css, Property, PseudoClass, Selector, Value};
+
+/// How CSS is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// No unnecessary whitespace.
+    #[default]
+    Minified,
+    /// One declaration per line, indented by two spaces per nesting level.
+    Pretty,
+}
+
+/// A style rule, e.g. `.card:hover { transform: scale(1.01) }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    selectors: Vec<Selector>,
+    declarations: Css,
+}
+
+impl Rule {
+    #[must_use]
+    pub fn new(selector: Selector) -> Self {
+        Self {
+            selectors: vec![selector],
+            declarations: Css::new(),
+        }
+    }
+
+    /// Also apply the rule to another selector, e.g. `.a, .b`.
+    #[must_use]
+    pub fn or(mut self, selector: Selector) -> Self {
+        self.selectors.push(selector);
+        self
+    }
+
+    /// Set a declaration, replacing any previous value for the property.
+    #[must_use]
+    pub fn set<V>(mut self, property: Property, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.declarations.insert(property, value);
+        self
+    }
+
+    /// Merge in several declarations, see [`Css::merge`].
+    #[must_use]
+    pub fn declarations(mut self, css: Css) -> Self {
+        self.declarations.merge(css);
+        self
+    }
+}
+
+/// Animation keyframes, i.e. `@keyframes`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes {
+    name: String,
+    frames: Vec<(String, Css)>,
+}
+
+impl Keyframes {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            frames: vec![],
+        }
+    }
+
+    /// Add a frame, where `at` is e.g. `from`, `to` or `50%`.
+    #[must_use]
+    pub fn frame(mut self, at: &str, css: Css) -> Self {
+        self.frames.push((at.into(), css));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Item {
+    Rule(Rule),
+    Media(String, Stylesheet),
+    Supports(String, Stylesheet),
+    FontFace(Css),
+    Keyframes(Keyframes),
+}
+
+/// A stylesheet, rendered into a [`Style`] element or a `.css` file.
+///
+/// ```
+/// use html_strong::css::{px, Color, Format, Property, Rule, Selector, Stylesheet};
+///
+/// let sheet = Stylesheet::new()
+///     .rule(Rule::new(Selector::class("cards")).set(Property::Gap, px(8)))
+///     .media(
+///         "(max-width: 750px)",
+///         Stylesheet::new().rule(Rule::new(Selector::class("cards")).set(Property::Gap, px(4))),
+///     );
+///
+/// assert_eq!(
+///     ".cards{gap:8px}@media (max-width: 750px){.cards{gap:4px}}",
+///     sheet.render(Format::Minified)
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stylesheet {
+    items: Vec<Item>,
+}
+
+impl Stylesheet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { items: vec![] }
+    }
+
+    #[must_use]
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.items.push(Item::Rule(rule));
+        self
+    }
+
+    /// Declare custom properties on `:root`.
+    /// Use [`Property::custom`] for the names.
+    #[must_use]
+    pub fn custom_properties(self, css: Css) -> Self {
+        self.rule(Rule::new(Selector::raw("").pseudo_class(PseudoClass::Root)).declarations(css))
+    }
+
+    /// Nest a stylesheet within `@media`, e.g. with the query `(max-width: 750px)`.
+    #[must_use]
+    pub fn media(mut self, query: &str, stylesheet: Self) -> Self {
+        self.items.push(Item::Media(query.into(), stylesheet));
+        self
+    }
+
+    /// Nest a stylesheet within `@supports`, e.g. with the condition `(display: grid)`.
+    #[must_use]
+    pub fn supports(mut self, condition: &str, stylesheet: Self) -> Self {
+        self.items
+            .push(Item::Supports(condition.into(), stylesheet));
+        self
+    }
+
+    /// Add a `@font-face`, typically declaring [`Property::FontFamily`] and [`Property::Src`].
+    #[must_use]
+    pub fn font_face(mut self, css: Css) -> Self {
+        self.items.push(Item::FontFace(css));
+        self
+    }
+
+    #[must_use]
+    pub fn keyframes(mut self, keyframes: Keyframes) -> Self {
+        self.items.push(Item::Keyframes(keyframes));
+        self
+    }
+
+    /// Append all items of another stylesheet.
+    #[must_use]
+    pub fn extend(mut self, other: Self) -> Self {
+        self.items.extend(other.items);
+        self
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Render the stylesheet to CSS text.
+    #[must_use]
+    pub fn render(&self, format: Format) -> String {
+        let mut out = String::new();
+        self.render_into(&mut out, format, 0);
+
+        if format == Format::Pretty && !out.is_empty() {
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Render into a `<style>` element.
+    ///
+    /// Any `</` is written as `<\/`, the same in CSS strings,
+    /// such that e.g. `content: "</style>"` cannot end the element early.
+    #[must_use]
+    pub fn to_style(&self, format: Format) -> Node {
+        Style.text(self.render(format).replace("</", "<\\/"))
+    }
+
+    /// Write the stylesheet to a `.css` file,
+    /// e.g. to be served and linked via [`crate::tags::Link::stylesheet`].
+    ///
+    /// # Errors
+    ///
+    /// See [`fs::write`].
+    pub fn write_file<P>(&self, path: P, format: Format) -> Result<(), io::Error>
+    where
+        P: AsRef<Path>,
+    {
+        fs::write(path, self.render(format))
+    }
+
+    fn render_into(&self, out: &mut String, format: Format, depth: usize) {
+        for (index, item) in self.items.iter().enumerate() {
+            if format == Format::Pretty && index > 0 {
+                out.push_str("\n\n");
+            }
+
+            match item {
+                Item::Rule(rule) => {
+                    let separator = if format == Format::Pretty { ", " } else { "," };
+                    let selectors = rule
+                        .selectors
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(separator);
+
+                    block(out, format, depth, &selectors, |out| {
+                        declarations(out, format, depth + 1, &rule.declarations);
+                    });
+                }
+                Item::Media(query, nested) => {
+                    block(out, format, depth, &format!("@media {query}"), |out| {
+                        nested.render_into(out, format, depth + 1);
+                    });
+                }
+                Item::Supports(condition, nested) => {
+                    block(
+                        out,
+                        format,
+                        depth,
+                        &format!("@supports {condition}"),
+                        |out| {
+                            nested.render_into(out, format, depth + 1);
+                        },
+                    );
+                }
+                Item::FontFace(css) => {
+                    block(out, format, depth, "@font-face", |out| {
+                        declarations(out, format, depth + 1, css);
+                    });
+                }
+                Item::Keyframes(keyframes) => {
+                    let prelude = format!("@keyframes {}", keyframes.name);
+
+                    block(out, format, depth, &prelude, |out| {
+                        for (index, (at, css)) in keyframes.frames.iter().enumerate() {
+                            if format == Format::Pretty && index > 0 {
+                                out.push('\n');
+                            }
+                            block(out, format, depth + 1, at, |out| {
+                                declarations(out, format, depth + 2, css);
+                            });
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn indent(out: &mut String, depth: usize) {
+    out.push_str(&"  ".repeat(depth));
+}
+
+/// Render `prelude { ... }`.
+fn block<F>(out: &mut String, format: Format, depth: usize, prelude: &str, contents: F)
+where
+    F: FnOnce(&mut String),
+{
+    match format {
+        Format::Minified => {
+            out.push_str(prelude);
+            out.push('{');
+            contents(out);
+            out.push('}');
+        }
+        Format::Pretty => {
+            indent(out, depth);
+            out.push_str(prelude);
+            out.push_str(" {\n");
+            contents(out);
+            out.push('\n');
+            indent(out, depth);
+            out.push('}');
+        }
+    }
+}
+
+fn declarations(out: &mut String, format: Format, depth: usize, css: &Css) {
+    match format {
+        Format::Minified => out.push_str(&css.to_string()),
+        Format::Pretty => {
+            let lines = css
+                .iter()
+                .map(|(property, value)| format!("{}{property}: {value};", "  ".repeat(depth)))
+                .collect::<Vec<_>>();
+
+            out.push_str(&lines.join("\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css::{percent, px, rem, Color, Keyword, PseudoClass, PseudoElement};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn candy() -> Stylesheet {
+        Stylesheet::new()
+            .custom_properties(Css::new().set(Property::custom("card-radius"), px(10)))
+            .rule(
+                Rule::new(Selector::class("card"))
+                    .set(Property::Padding, rem(2))
+                    .set(Property::BorderRadius, "var(--card-radius)"),
+            )
+            .rule(
+                Rule::new(Selector::class("card").pseudo_class(PseudoClass::Hover))
+                    .set(Property::Transform, "scale(1.01)"),
+            )
+            .rule(
+                Rule::new(
+                    Selector::class("card")
+                        .pseudo_class(PseudoClass::NthChild(2, 1))
+                        .not(Selector::class("card-1")),
+                )
+                .set(Property::Opacity, 0.5),
+            )
+            .rule(
+                Rule::new(Selector::class("card__link"))
+                    .or(Selector::class("card__exit"))
+                    .set(Property::TextDecoration, Keyword::None)
+                    .set(Property::Color, Color::Rgba(255, 255, 255, 0.9)),
+            )
+            .media(
+                "(max-width: 750px)",
+                Stylesheet::new()
+                    .rule(Rule::new(Selector::class("cards")).set(Property::Width, percent(100))),
+            )
+    }
+
+    #[test]
+    fn render_minified() {
+        let expected = concat!(
+            ":root{--card-radius:10px}",
+            ".card{padding:2rem;border-radius:var(--card-radius)}",
+            ".card:hover{transform:scale(1.01)}",
+            ".card:nth-child(2n+1):not(.card-1){opacity:0.5}",
+            ".card__link,.card__exit{text-decoration:none;color:rgba(255, 255, 255, 0.9)}",
+            "@media (max-width: 750px){.cards{width:100%}}"
+        );
+
+        assert_eq!(expected, candy().render(Format::Minified));
+    }
+
+    #[test]
+    fn render_pretty() {
+        let expected = r#":root {
+  --card-radius: 10px;
+}
+
+.card {
+  padding: 2rem;
+  border-radius: var(--card-radius);
+}
+
+.card:hover {
+  transform: scale(1.01);
+}
+
+.card:nth-child(2n+1):not(.card-1) {
+  opacity: 0.5;
+}
+
+.card__link, .card__exit {
+  text-decoration: none;
+  color: rgba(255, 255, 255, 0.9);
+}
+
+@media (max-width: 750px) {
+  .cards {
+    width: 100%;
+  }
+}
+"#;
+
+        assert_eq!(expected, candy().render(Format::Pretty));
+    }
+
+    #[test]
+    fn render_at_rules() {
+        let sheet = Stylesheet::new()
+            .font_face(
+                Css::new()
+                    .set(Property::FontFamily, "\"Domine\"")
+                    .set(Property::Src, "url(/static/domine.woff2) format(\"woff2\")"),
+            )
+            .keyframes(
+                Keyframes::new("fade")
+                    .frame("from", Css::new().set(Property::Opacity, 0))
+                    .frame("to", Css::new().set(Property::Opacity, 1)),
+            )
+            .supports(
+                "(display: grid)",
+                Stylesheet::new().rule(
+                    Rule::new(Selector::class("cards")).set(Property::Display, Keyword::Grid),
+                ),
+            );
+
+        let expected = concat!(
+            r#"@font-face{font-family:"Domine";src:url(/static/domine.woff2) format("woff2")}"#,
+            "@keyframes fade{from{opacity:0}to{opacity:1}}",
+            "@supports (display: grid){.cards{display:grid}}"
+        );
+        assert_eq!(expected, sheet.render(Format::Minified));
+    }
+
+    #[test]
+    fn render_into_style_element() {
+        let sheet =
+            Stylesheet::new().rule(Rule::new(Selector::id("main")).set(Property::Margin, 0));
+
+        assert_eq!(
+            "<style>#main{margin:0}</style>",
+            sheet.to_style(Format::Minified).render_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn style_element_cannot_be_closed_early() {
+        let sheet = Stylesheet::new().rule(
+            Rule::new(Selector::class("quote").pseudo_element(PseudoElement::After))
+                .set(Property::Content, r#""</style><script>x()</script>""#),
+        );
+
+        assert_eq!(
+            r#"<style>.quote::after{content:"<\/style><script>x()<\/script>"}</style>"#,
+            sheet.to_style(Format::Minified).render_string().unwrap()
+        );
+    }
+}
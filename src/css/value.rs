@@ -0,0 +1,308 @@
+use std::fmt;
+
+/// Units for [`Length`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Px,
+    Pt,
+    Cm,
+    Mm,
+    In,
+    Em,
+    Rem,
+    Ex,
+    Ch,
+    Percent,
+    Vw,
+    Vh,
+    Vmin,
+    Vmax,
+    Fr,
+}
+
+impl Unit {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Px => "px",
+            Self::Pt => "pt",
+            Self::Cm => "cm",
+            Self::Mm => "mm",
+            Self::In => "in",
+            Self::Em => "em",
+            Self::Rem => "rem",
+            Self::Ex => "ex",
+            Self::Ch => "ch",
+            Self::Percent => "%",
+            Self::Vw => "vw",
+            Self::Vh => "vh",
+            Self::Vmin => "vmin",
+            Self::Vmax => "vmax",
+            Self::Fr => "fr",
+        }
+    }
+}
+
+/// A length, e.g. `12px` or `50%`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    pub value: f64,
+    pub unit: Unit,
+}
+
+impl Length {
+    #[must_use]
+    pub const fn new(value: f64, unit: Unit) -> Self {
+        Self { value, unit }
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit.as_str())
+    }
+}
+
+macro_rules! length_fns {
+    ( $( $fn:ident => $unit:ident ),* ) => {
+        $(
+            #[doc = concat!("A length in `", stringify!($unit), "`.")]
+            #[must_use]
+            pub fn $fn(value: impl Into<f64>) -> Length {
+                Length::new(value.into(), Unit::$unit)
+            }
+        )*
+    };
+}
+
+length_fns![
+    px => Px,
+    pt => Pt,
+    em => Em,
+    rem => Rem,
+    ch => Ch,
+    percent => Percent,
+    vw => Vw,
+    vh => Vh,
+    fr => Fr
+];
+
+/// A color.
+///
+/// [`Color::Rgb`] renders as hex, e.g. `#ff6600`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    Rgb(u8, u8, u8),
+    Rgba(u8, u8, u8, f64),
+    Transparent,
+    CurrentColor,
+    Black,
+    White,
+    Gray,
+    Red,
+    Green,
+    Blue,
+    Orange,
+}
+
+impl Color {
+    /// Parse a hex color such as `#ff6600` or `#f60`.
+    #[must_use]
+    pub fn hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let channel = |i: usize, len: usize| {
+            let digits = hex.get(i * len..(i + 1) * len)?;
+            let value = u8::from_str_radix(digits, 16).ok()?;
+
+            Some(if len == 1 { value * 17 } else { value })
+        };
+
+        let len = match hex.len() {
+            3 => 1,
+            6 => 2,
+            _ => return None,
+        };
+
+        Some(Self::Rgb(
+            channel(0, len)?,
+            channel(1, len)?,
+            channel(2, len)?,
+        ))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rgb(red, green, blue) => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            Self::Rgba(red, green, blue, alpha) => {
+                write!(f, "rgba({red}, {green}, {blue}, {alpha})")
+            }
+            Self::Transparent => write!(f, "transparent"),
+            Self::CurrentColor => write!(f, "currentcolor"),
+            Self::Black => write!(f, "black"),
+            Self::White => write!(f, "white"),
+            Self::Gray => write!(f, "gray"),
+            Self::Red => write!(f, "red"),
+            Self::Green => write!(f, "green"),
+            Self::Blue => write!(f, "blue"),
+            Self::Orange => write!(f, "orange"),
+        }
+    }
+}
+
+macro_rules! keywords {
+    ( $( $variant:ident => $keyword:literal ),* $(,)? ) => {
+        /// Keyword values.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Keyword {
+            $( $variant, )*
+        }
+
+        impl Keyword {
+            const fn as_str(self) -> &'static str {
+                match self {
+                    $( Self::$variant => $keyword, )*
+                }
+            }
+        }
+    };
+}
+
+keywords![
+    Absolute => "absolute",
+    Auto => "auto",
+    Block => "block",
+    Bold => "bold",
+    Bottom => "bottom",
+    Center => "center",
+    Collapse => "collapse",
+    Contents => "contents",
+    Dashed => "dashed",
+    Dotted => "dotted",
+    Fixed => "fixed",
+    Flex => "flex",
+    FlexEnd => "flex-end",
+    FlexStart => "flex-start",
+    Grid => "grid",
+    Hidden => "hidden",
+    Inherit => "inherit",
+    Initial => "initial",
+    Inline => "inline",
+    InlineBlock => "inline-block",
+    InlineFlex => "inline-flex",
+    Italic => "italic",
+    Justify => "justify",
+    Left => "left",
+    Middle => "middle",
+    None => "none",
+    Normal => "normal",
+    Nowrap => "nowrap",
+    Pointer => "pointer",
+    Relative => "relative",
+    Right => "right",
+    Row => "row",
+    Column => "column",
+    Scroll => "scroll",
+    SpaceBetween => "space-between",
+    Solid => "solid",
+    Static => "static",
+    Sticky => "sticky",
+    Top => "top",
+    Underline => "underline",
+    Unset => "unset",
+    Visible => "visible",
+    Wrap => "wrap",
+];
+
+impl fmt::Display for Keyword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The value of a declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Length(Length),
+    Number(f64),
+    Color(Color),
+    Keyword(Keyword),
+    /// Several values separated by spaces, e.g. `1px solid white`.
+    List(Vec<Self>),
+    /// Anything not (yet) typed, rendered as is.
+    Raw(String),
+}
+
+impl Value {
+    /// Several values separated by spaces, e.g. `1px solid white`.
+    pub fn list<I, V>(values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Self>,
+    {
+        Self::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(length) => write!(f, "{length}"),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Color(color) => write!(f, "{color}"),
+            Self::Keyword(keyword) => write!(f, "{keyword}"),
+            Self::List(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                Ok(())
+            }
+            Self::Raw(raw) => write!(f, "{raw}"),
+        }
+    }
+}
+
+impl From<Length> for Value {
+    fn from(length: Length) -> Self {
+        Self::Length(length)
+    }
+}
+
+impl From<Color> for Value {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<Keyword> for Value {
+    fn from(keyword: Keyword) -> Self {
+        Self::Keyword(keyword)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(number: i32) -> Self {
+        Self::Number(number.into())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(raw: &str) -> Self {
+        Self::Raw(raw.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(raw: String) -> Self {
+        Self::Raw(raw)
+    }
+}
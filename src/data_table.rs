@@ -0,0 +1,568 @@
+use std::fmt;
+
+use url::form_urlencoded;
+
+use crate::{
+    attributes::href::SafeUrl,
+    css::{Css, Keyword, Property},
+    document_tree::{o, Node},
+    global_attributes::{
+        aria::{Aria, Sort},
+        escape_attribute_value,
+    },
+    science_lab::NodeExt,
+    tags::{td::td, th::Scope, Caption, Table, Tbody, Td, Th, Thead, Tr, A},
+};
+
+/// The query parameter naming the column sorted by.
+const SORT: &str = "sort";
+/// The query parameter giving the [`Direction`].
+const ORDER: &str = "order";
+
+/// How the cells of a [`Column`] are aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    /// Usually the choice for numbers.
+    Right,
+}
+
+impl Align {
+    const fn keyword(self) -> Keyword {
+        match self {
+            Self::Left => Keyword::Left,
+            Self::Center => Keyword::Center,
+            Self::Right => Keyword::Right,
+        }
+    }
+}
+
+/// The direction a [`DataTable`] is sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+impl Direction {
+    /// The value of the `order` query parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ascending => "asc",
+            Self::Descending => "desc",
+        }
+    }
+
+    const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+type Cell<'a, R> = Box<dyn Fn(&R) -> Node + 'a>;
+type RowClass<'a, R> = Box<dyn Fn(usize, &R) -> Option<String> + 'a>;
+
+/// A column of a [`DataTable`].
+pub struct Column<'a, R> {
+    header: String,
+    cell: Cell<'a, R>,
+    align: Option<Align>,
+    sort_key: Option<String>,
+}
+
+impl<'a, R> Column<'a, R> {
+    /// A column whose cells contain the node built from each row.
+    pub fn new<F, N>(header: &str, cell: F) -> Self
+    where
+        F: Fn(&R) -> N + 'a,
+        N: NodeExt,
+    {
+        Self {
+            header: header.to_string(),
+            cell: Box::new(move |row| o(td()).kid(cell(row))),
+            align: None,
+            sort_key: None,
+        }
+    }
+
+    /// A column whose cells contain text from each row, which is escaped.
+    pub fn text<F, T>(header: &str, cell: F) -> Self
+    where
+        F: Fn(&R) -> T + 'a,
+        T: fmt::Display,
+    {
+        Self {
+            header: header.to_string(),
+            cell: Box::new(move |row| o(td()).text(escape_attribute_value(&cell(row).to_string()))),
+            align: None,
+            sort_key: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Link the header to sorting by this column, with `key` as the `sort` query parameter.
+    /// See [`DataTable::sort_links`].
+    #[must_use]
+    pub fn sortable(mut self, key: &str) -> Self {
+        self.sort_key = Some(key.to_string());
+        self
+    }
+
+    fn aligned(&self, node: Node) -> Node {
+        match self.align {
+            Some(align) => node.add_css(Css::new().set(Property::TextAlign, align.keyword())),
+            None => node,
+        }
+    }
+}
+
+impl<R> fmt::Debug for Column<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Column")
+            .field("header", &self.header)
+            .field("align", &self.align)
+            .field("sort_key", &self.sort_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Renders rows as an accessible table, with a header per [`Column`].
+///
+/// ```
+/// use html_strong::data_table::{Align, Column, DataTable};
+///
+/// struct Story {
+///     title: &'static str,
+///     points: u32,
+/// }
+///
+/// let stories = [Story { title: "Show HN", points: 42 }];
+///
+/// let table = DataTable::new(vec![
+///     Column::text("Title", |story: &Story| story.title),
+///     Column::text("Points", |story: &Story| story.points)
+///         .align(Align::Right)
+///         .sortable("points"),
+/// ])
+/// .caption("Top stories")
+/// .sort_links("page=2&sort=points&order=asc")
+/// .render(stories);
+///
+/// let html = table.render_string().unwrap();
+/// assert!(html.contains(r#"<a href="?page=2&amp;sort=points&amp;order=desc">Points</a>"#));
+/// ```
+pub struct DataTable<'a, R> {
+    columns: Vec<Column<'a, R>>,
+    caption: Option<String>,
+    query: Option<Vec<(String, String)>>,
+    row_classes: Vec<RowClass<'a, R>>,
+    empty: String,
+}
+
+impl<'a, R> DataTable<'a, R> {
+    #[must_use]
+    pub fn new(columns: Vec<Column<'a, R>>) -> Self {
+        Self {
+            columns,
+            caption: None,
+            query: None,
+            row_classes: vec![],
+            empty: "Nothing to show.".to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn caption(mut self, caption: &str) -> Self {
+        self.caption = Some(caption.to_string());
+        self
+    }
+
+    /// Link the headers of sortable columns, given the query string of the current request.
+    ///
+    /// The links keep other query parameters, e.g. filters,
+    /// and set `sort` to the column's key and `order` to `asc` or `desc`.
+    /// The column currently sorted by is marked with `aria-sort`,
+    /// and its link reverses the order.
+    #[must_use]
+    pub fn sort_links(mut self, query: &str) -> Self {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        self.query = Some(
+            form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+        );
+        self
+    }
+
+    /// Add the classes to odd and even rows, counting from one, e.g. for striped rows.
+    #[must_use]
+    pub fn zebra(self, odd: &str, even: &str) -> Self {
+        let (odd, even) = (odd.to_string(), even.to_string());
+
+        self.row_class(move |index, _| {
+            Some(if index % 2 == 0 {
+                odd.clone()
+            } else {
+                even.clone()
+            })
+        })
+    }
+
+    /// Add a class to rows, given the row's index from zero, e.g. to highlight some rows.
+    #[must_use]
+    pub fn row_class<F>(mut self, row_class: F) -> Self
+    where
+        F: Fn(usize, &R) -> Option<String> + 'a,
+    {
+        self.row_classes.push(Box::new(row_class));
+        self
+    }
+
+    /// The text shown in a single row if there are no rows.
+    #[must_use]
+    pub fn empty(mut self, text: &str) -> Self {
+        self.empty = text.to_string();
+        self
+    }
+
+    /// The column sorted by, and in which direction, according to the query.
+    fn current_sort(&self) -> Option<(&str, Direction)> {
+        let query = self.query.as_ref()?;
+        let param = |name: &str| {
+            query
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let direction = match param(ORDER) {
+            Some("desc") => Direction::Descending,
+            _ => Direction::Ascending,
+        };
+
+        param(SORT).map(|key| (key, direction))
+    }
+
+    fn sort_link(&self, key: &str, direction: Direction) -> SafeUrl {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+
+        for (name, value) in self.query.iter().flatten() {
+            if name != SORT && name != ORDER {
+                query.append_pair(name, value);
+            }
+        }
+        query.append_pair(SORT, key);
+        query.append_pair(ORDER, direction.as_str());
+
+        SafeUrl::trusted(&format!("?{}", query.finish()))
+    }
+
+    fn header(&self, column: &Column<'a, R>) -> Node {
+        let header = escape_attribute_value(&column.header);
+        let th = column.aligned(o(Th::scope(Scope::Col)));
+
+        let Some(key) = column.sort_key.as_deref().filter(|_| self.query.is_some()) else {
+            return th.text(header);
+        };
+
+        let (th, direction) = match self.current_sort() {
+            Some((current, direction)) if current == key => {
+                let sort = match direction {
+                    Direction::Ascending => Sort::Ascending,
+                    Direction::Descending => Sort::Descending,
+                };
+                (th.add_attr(Aria::Sort(sort)), direction.reversed())
+            }
+            _ => (th, Direction::Ascending),
+        };
+
+        th.kid(A::href(self.sort_link(key, direction)).text(header))
+    }
+
+    /// Render the table, with a row per item.
+    pub fn render<I>(self, rows: I) -> Node
+    where
+        I: IntoIterator<Item = R>,
+    {
+        let mut table = o(Table);
+
+        if let Some(caption) = &self.caption {
+            table = table.kid(Caption.text(escape_attribute_value(caption)));
+        }
+
+        let headers = self
+            .columns
+            .iter()
+            .fold(o(Tr), |tr, column| tr.kid(self.header(column)));
+        table = table.kid(Thead.kid(headers));
+
+        let mut body = o(Tbody);
+        let mut is_empty = true;
+
+        for (index, row) in rows.into_iter().enumerate() {
+            is_empty = false;
+
+            let mut tr = self.columns.iter().fold(o(Tr), |tr, column| {
+                tr.kid(column.aligned((column.cell)(&row)))
+            });
+
+            for class in self.row_classes.iter().filter_map(|f| f(index, &row)) {
+                tr = tr.add_class(&class);
+            }
+
+            body = body.kid(tr);
+        }
+
+        if is_empty {
+            let cell = Td::colspan(self.columns.len()).unwrap_or_default();
+            body = body.kid(
+                Tr.class("empty")
+                    .kid(o(cell).text(escape_attribute_value(&self.empty))),
+            );
+        }
+
+        table.kid(body)
+    }
+}
+
+impl<R> fmt::Debug for DataTable<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataTable")
+            .field("columns", &self.columns)
+            .field("caption", &self.caption)
+            .field("query", &self.query)
+            .field("empty", &self.empty)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A row serialized into its fields, see [`DataTable::render_serialized`].
+#[cfg(feature = "serde")]
+pub type Fields = serde_json::Map<String, serde_json::Value>;
+
+/// The fields of a serialized row in order, which [`Fields`] does not keep.
+#[cfg(feature = "serde")]
+struct OrderedFields(Vec<(String, serde_json::Value)>);
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OrderedFields {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = OrderedFields;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a row of fields")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut fields = vec![];
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(OrderedFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn field_text(value: Option<&serde_json::Value>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => String::new(),
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Column<'_, Fields> {
+    /// A column showing a field of serialized rows.
+    #[must_use]
+    pub fn field(header: &str, field: &str) -> Self {
+        let field = field.to_string();
+
+        Self::text(header, move |row: &Fields| field_text(row.get(&field)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl DataTable<'_, Fields> {
+    /// Render rows of any [`serde::Serialize`] type, typically structs.
+    ///
+    /// Without columns, there is a column per field of the first row, in order,
+    /// with the field's name as header.
+    ///
+    /// # Errors
+    ///
+    /// If a row does not serialize into fields, e.g. if it is a number.
+    pub fn render_serialized<I, S>(mut self, rows: I) -> Result<Node, serde_json::Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: serde::Serialize,
+    {
+        // Going through the text keeps the fields in the order they are serialized.
+        let rows = rows
+            .into_iter()
+            .map(|row| serde_json::from_str::<OrderedFields>(&serde_json::to_string(&row)?))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if self.columns.is_empty() {
+            if let Some(first) = rows.first() {
+                self.columns = first
+                    .0
+                    .iter()
+                    .map(|(field, _)| Column::field(field, field))
+                    .collect();
+            }
+        }
+
+        Ok(self.render(
+            rows.into_iter()
+                .map(|row| row.0.into_iter().collect::<Fields>()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    struct Story {
+        title: &'static str,
+        points: u32,
+    }
+
+    const STORIES: [Story; 3] = [
+        Story {
+            title: "Rust &lt;3",
+            points: 300,
+        },
+        Story {
+            title: "Show HN",
+            points: 42,
+        },
+        Story {
+            title: "Ask HN",
+            points: 7,
+        },
+    ];
+
+    fn columns<'a>() -> Vec<Column<'a, Story>> {
+        vec![
+            Column::new("Title", |story: &Story| A::href("/item").text(story.title)),
+            Column::text("Points", |story: &Story| story.points)
+                .align(Align::Right)
+                .sortable("points"),
+        ]
+    }
+
+    #[test]
+    fn render_rows() {
+        let result = DataTable::new(columns())
+            .caption("Top & best")
+            .zebra("odd", "even")
+            .row_class(|_, story| (story.points > 100).then(|| "hot".to_string()))
+            .render(STORIES)
+            .render_string()
+            .unwrap();
+
+        assert_eq!(
+            concat!(
+                "<table><caption>Top &amp; best</caption>",
+                r#"<thead><tr><th scope="col">Title</th><th style="text-align:right" scope="col">Points</th></tr></thead>"#,
+                "<tbody>",
+                r#"<tr class="odd hot"><td><a href="/item">Rust &lt;3</a></td><td style="text-align:right">300</td></tr>"#,
+                r#"<tr class="even"><td><a href="/item">Show HN</a></td><td style="text-align:right">42</td></tr>"#,
+                r#"<tr class="odd"><td><a href="/item">Ask HN</a></td><td style="text-align:right">7</td></tr>"#,
+                "</tbody></table>"
+            ),
+            result
+        );
+    }
+
+    #[test]
+    fn render_sort_links() {
+        let unsorted = DataTable::new(columns())
+            .sort_links("?q=rust&p=2")
+            .render([])
+            .render_string()
+            .unwrap();
+
+        assert!(unsorted.contains(
+            r#"<th style="text-align:right" scope="col"><a href="?q=rust&amp;p=2&amp;sort=points&amp;order=asc">Points</a></th>"#
+        ));
+
+        let sorted = DataTable::new(columns())
+            .sort_links("sort=points&q=a+b&order=asc")
+            .render([])
+            .render_string()
+            .unwrap();
+
+        assert!(sorted.contains(
+            r#"<th style="text-align:right" aria-sort="ascending" scope="col"><a href="?q=a+b&amp;sort=points&amp;order=desc">Points</a></th>"#
+        ));
+    }
+
+    #[test]
+    fn render_empty() {
+        let result = DataTable::new(columns())
+            .empty("No stories <yet>.")
+            .render([])
+            .render_string()
+            .unwrap();
+
+        assert!(result.ends_with(
+            r#"<tbody><tr class="empty"><td colspan="2">No stories &lt;yet&gt;.</td></tr></tbody></table>"#
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn render_serialized() {
+        #[derive(serde::Serialize)]
+        struct User {
+            name: String,
+            karma: i64,
+            about: Option<String>,
+        }
+
+        let users = [User {
+            name: "pg".to_string(),
+            karma: 155_000,
+            about: None,
+        }];
+
+        let result = DataTable::new(vec![])
+            .render_serialized(users)
+            .unwrap()
+            .render_string()
+            .unwrap();
+
+        assert_eq!(
+            concat!(
+                r#"<table><thead><tr><th scope="col">name</th><th scope="col">karma</th><th scope="col">about</th></tr></thead>"#,
+                "<tbody><tr><td>pg</td><td>155000</td><td></td></tr></tbody></table>"
+            ),
+            result
+        );
+        assert!(DataTable::new(vec![]).render_serialized([1, 2]).is_err());
+    }
+}
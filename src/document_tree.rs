@@ -1,7 +1,10 @@
-use std::{collections::HashSet, io};
+use std::io;
 
 use crate::{
-    global_attributes::{Attribute, Class, Id, Style, Title},
+    css::{Css, ScopedStyle},
+    global_attributes::{
+        escape_attribute_value, Attribute, Class, Dir, Event, Id, Lang, Listener, Title,
+    },
     science_lab::NodeExt,
     tags::{invisible::Invisible, root::Root, Tag},
 };
@@ -9,7 +12,10 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Node {
     global_attributes: Vec<Box<dyn Attribute>>,
-    classes: HashSet<Class>,
+    classes: Vec<Class>,
+    style: Css,
+    scoped_styles: Vec<ScopedStyle>,
+    listeners: Vec<Listener>,
     tag: Box<dyn Tag>,
     text: Option<String>,
     children: Vec<Node>,
@@ -37,7 +43,10 @@ impl Node {
             children: vec![],
             text: None,
             global_attributes: vec![],
-            classes: HashSet::new(),
+            classes: vec![],
+            style: Css::new(),
+            scoped_styles: vec![],
+            listeners: vec![],
         }
     }
 
@@ -77,14 +86,39 @@ impl Node {
                 let all_classes = self
                     .classes
                     .iter()
-                    .cloned()
+                    .map(Class::as_str)
                     .collect::<Vec<_>>()
-                    .as_slice()
                     .join(" ");
+                let all_classes = escape_attribute_value(&all_classes);
 
                 write!(writer, " class=\"{all_classes}\"")?;
             }
 
+            if !self.style.is_empty() {
+                let style = escape_attribute_value(&self.style.to_string());
+
+                write!(writer, " style=\"{style}\"")?;
+            }
+
+            let mut events: Vec<Event> = vec![];
+            for listener in &self.listeners {
+                if !events.contains(&listener.event()) {
+                    events.push(listener.event());
+                }
+            }
+
+            for event in events {
+                let hooks = self
+                    .listeners
+                    .iter()
+                    .filter(|listener| listener.event() == event)
+                    .map(Listener::hook)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                write!(writer, " data-on-{}=\"{hooks}\"", event.as_str())?;
+            }
+
             let mut render_attr =
                 |attr: &dyn Attribute| write!(writer, " {}=\"{}\"", attr.name(), attr.value());
 
@@ -198,20 +232,220 @@ impl Node {
         self
     }
 
+    /// Add inline style declarations such as `line-height:12pt; height:10px;`.
+    ///
+    /// Declarations are merged with any previously added ones,
+    /// where the last value set for a property wins.
+    #[must_use]
+    pub fn add_style(self, style: &str) -> Self {
+        self.add_css(Css::parse(style))
+    }
+
+    /// Add typed inline style declarations, see [`Node::add_style`].
     #[must_use]
-    pub fn add_style(mut self, style: &str) -> Self {
-        self.global_attributes.push(Box::new(Style::new(style)));
+    pub fn add_css(mut self, css: Css) -> Self {
+        self.style.merge(css);
         self
     }
 
+    /// Add classes, separated by whitespace.
+    /// Classes already present are not repeated, and empty input adds nothing.
     #[must_use]
     pub fn add_class(mut self, class: &str) -> Self {
-        for class in class.split_ascii_whitespace() {
-            self.classes.insert(class.to_string());
+        for class in Class::split(class) {
+            if !self.classes.contains(&class) {
+                self.classes.push(class);
+            }
         }
         self
     }
 
+    /// Remove classes, separated by whitespace.
+    #[must_use]
+    pub fn remove_class(mut self, class: &str) -> Self {
+        let remove = Class::split(class).collect::<Vec<_>>();
+
+        self.classes.retain(|class| !remove.contains(class));
+        self
+    }
+
+    /// Add each class which is not present, and remove each class which is.
+    /// Classes are separated by whitespace.
+    #[must_use]
+    pub fn toggle_class(mut self, class: &str) -> Self {
+        for class in Class::split(class) {
+            match self.classes.iter().position(|c| *c == class) {
+                Some(index) => {
+                    self.classes.remove(index);
+                }
+                None => self.classes.push(class),
+            }
+        }
+        self
+    }
+
+    /// Add classes only if the condition holds, see [`Node::add_class`].
+    #[must_use]
+    pub fn add_class_if(self, condition: bool, class: &str) -> Self {
+        if condition {
+            self.add_class(class)
+        } else {
+            self
+        }
+    }
+
+    /// Add each class whose condition holds, see [`Node::add_class`].
+    #[must_use]
+    pub fn add_classes_if<'a, I>(self, classes: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, bool)>,
+    {
+        classes.into_iter().fold(self, |node, (class, condition)| {
+            node.add_class_if(condition, class)
+        })
+    }
+
+    #[must_use]
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c.as_str() == class)
+    }
+
+    /// The node's classes, in the order they were added.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(Class::as_str)
+    }
+
+    /// Add a class local to a component, see [`ScopedStyle`].
+    ///
+    /// The hashed class name is added,
+    /// and the component's styles are remembered such that they can be collected
+    /// via [`Node::scoped_styles`] when rendering a document.
+    #[must_use]
+    pub fn add_scoped_class(mut self, style: &ScopedStyle, class: &str) -> Self {
+        if !self.scoped_styles.contains(style) {
+            self.scoped_styles.push(style.clone());
+        }
+        self.add_class(&style.class(class))
+    }
+
+    /// All component styles used in this (sub)tree, in order of first use and without duplicates.
+    #[must_use]
+    pub fn scoped_styles(&self) -> Vec<ScopedStyle> {
+        let mut styles = vec![];
+        self.collect_scoped_styles(&mut styles);
+        styles
+    }
+
+    fn collect_scoped_styles(&self, styles: &mut Vec<ScopedStyle>) {
+        for style in &self.scoped_styles {
+            if !styles.contains(style) {
+                styles.push(style.clone());
+            }
+        }
+
+        for child in &self.children {
+            child.collect_scoped_styles(styles);
+        }
+    }
+
+    /// Handle an event without an inline handler attribute, see [`Listener`].
+    #[must_use]
+    pub fn add_listener(mut self, event: Event, script: &str) -> Self {
+        let listener = Listener::new(event, script);
+
+        if !self.listeners.contains(&listener) {
+            self.listeners.push(listener);
+        }
+        self
+    }
+
+    /// All listeners used in this (sub)tree, in order of first use and without duplicates.
+    #[must_use]
+    pub fn listeners(&self) -> Vec<Listener> {
+        let mut listeners = vec![];
+        self.collect_listeners(&mut listeners);
+        listeners
+    }
+
+    fn collect_listeners(&self, listeners: &mut Vec<Listener>) {
+        for listener in &self.listeners {
+            if !listeners.contains(listener) {
+                listeners.push(listener.clone());
+            }
+        }
+
+        for child in &self.children {
+            child.collect_listeners(listeners);
+        }
+    }
+
+    /// Set an attribute, replacing any previously added attribute of the same name.
+    pub fn set_attr(&mut self, attribute: impl Attribute + 'static) {
+        self.global_attributes
+            .retain(|attr| attr.name() != attribute.name());
+        self.global_attributes.push(Box::new(attribute));
+    }
+
+    /// Whether the node has an attribute of the given name,
+    /// either added to the node or given by its tag.
+    #[must_use]
+    pub fn has_attr(&self, name: &str) -> bool {
+        self.global_attributes
+            .iter()
+            .any(|attr| attr.name() == name)
+            || self
+                .tag
+                .attributes()
+                .is_some_and(|attrs| attrs.iter().any(|attr| attr.name() == name))
+    }
+
+    /// The name of the node's tag, e.g. `script`.
+    /// Nodes without tags, such as the root or text, have no name.
+    #[must_use]
+    pub fn tag_name(&self) -> Option<&'static str> {
+        self.tag.open_tag().then(|| self.tag.name())
+    }
+
+    /// Render the node's content without its own tags,
+    /// i.e. what a browser sees as the content of e.g. a `<script>`.
+    ///
+    /// # Errors
+    ///
+    /// See [`render_writer`].
+    pub fn inner_html(&self) -> Result<String, io::Error> {
+        let mut buf = vec![];
+
+        if let Some(text) = self.text() {
+            buf.extend_from_slice(text.as_bytes());
+        }
+
+        for child in &self.children {
+            child.render_writer(&mut buf)?;
+        }
+
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    /// Call the function on this node and every node below it, parents before children.
+    pub fn visit_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Self),
+    {
+        f(self);
+
+        for child in &mut self.children {
+            child.visit_mut(f);
+        }
+    }
+
+    /// Insert a child before all existing children.
+    pub fn push_kid_front<K>(&mut self, kid: K)
+    where
+        K: NodeExt,
+    {
+        self.children.insert(0, kid.into_node());
+    }
+
     /// Set the node's id.
     #[must_use]
     pub fn set_id(mut self, id: &str) -> Self {
@@ -219,6 +453,23 @@ impl Node {
         self
     }
 
+    /// Set the node's language.
+    ///
+    /// If the language is written right-to-left, `dir="rtl"` is set as well,
+    /// replacing any direction set before.
+    #[must_use]
+    pub fn set_lang(mut self, lang: Lang) -> Self {
+        let dir = lang.direction();
+
+        self.global_attributes
+            .retain(|attr| attr.name() != "lang" && (dir != Dir::Rtl || attr.name() != "dir"));
+        self.global_attributes.push(Box::new(lang));
+        if dir == Dir::Rtl {
+            self.global_attributes.push(Box::new(dir));
+        }
+        self
+    }
+
     /// Set the node's title.
     #[must_use]
     pub fn set_title(mut self, title: &str) -> Self {
@@ -248,6 +499,12 @@ impl Node {
         let node: Self = Invisible.into_node();
         self.kid(node.set_text(text))
     }
+
+    /// Add text after the existing children, see [`Node::add_text`].
+    pub fn push_text(&mut self, text: &str) {
+        let node: Self = Invisible.into_node();
+        self.children.push(node.set_text(text));
+    }
 }
 
 #[cfg(test)]
@@ -491,12 +748,30 @@ mod tests {
         let _builds = Node::root().add_attr((key, value));
     }
 
+    #[test]
+    fn style_merges() {
+        use crate::css::{px, Color, Property};
+
+        let expected = r#"<td style="line-height:12pt;height:12px;color:#ff6600"></td>"#;
+        let rendered = o(crate::tags::Td::default())
+            .add_style("line-height:12pt; height:10px;")
+            .add_css(
+                Css::new()
+                    .set(Property::Height, px(12))
+                    .set(Property::Color, Color::Rgb(255, 102, 0)),
+            )
+            .render_string()
+            .unwrap();
+
+        assert_eq!(rendered, expected);
+    }
+
     #[test]
     fn custom_attribute() {
         let expected = r#"<html op="news" lang="en"></html>"#;
         let rendered = o(Html)
             .add_attr(("op", "news"))
-            .add_attr(Lang::English)
+            .add_attr(Lang::ENGLISH)
             .render_string()
             .unwrap();
 
@@ -11,17 +11,67 @@ macro_rules! decl_mod_pub_use {
     };
 }
 
-decl_mod_pub_use![id::Id, class::Class, lang::Lang, style::Style, title::Title];
+decl_mod_pub_use![
+    id::Id,
+    class::Class,
+    lang::Lang,
+    style::Style,
+    title::Title,
+    data::Data,
+    aria::Aria,
+    hidden::Hidden,
+    tabindex::Tabindex,
+    dir::Dir,
+    contenteditable::ContentEditable,
+    draggable::Draggable,
+    spellcheck::Spellcheck,
+    translate::Translate,
+    accesskey::AccessKey,
+    autocapitalize::Autocapitalize,
+    autofocus::Autofocus,
+    inert::Inert,
+    enterkeyhint::EnterKeyHint,
+    inputmode::InputMode,
+    nonce::Nonce,
+    popover::Popover,
+    slot::Slot,
+    part::Part,
+    event::Event
+];
+
+pub use event::{Listener, On};
 
 #[clonable]
 pub trait Attribute: Send + fmt::Debug + Clone {
     /// The attribute's name.
-    fn name(&self) -> &'static str;
+    ///
+    /// Most attributes have a fixed name, but some families such as `data-*`
+    /// are named at runtime, hence the name may borrow from the attribute.
+    fn name(&self) -> &str;
 
     /// The attribute's value.
     fn value(&self) -> String;
 }
 
+/// Escape a value such that it can be rendered within a double quoted attribute.
+#[must_use]
+pub fn escape_attribute_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
 impl Attribute for (&'static str, String) {
     fn name(&self) -> &'static str {
         self.0
@@ -41,3 +91,96 @@ impl Attribute for (&'static str, &str) {
         self.1.to_string()
     }
 }
+
+/// An attribute without a value, such as `disabled`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Flag(pub(crate) &'static str);
+
+impl Attribute for Flag {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn value(&self) -> String {
+        String::new()
+    }
+}
+
+/// Any other attribute of a tag, whose value is escaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Other {
+    pub(crate) name: &'static str,
+    pub(crate) value: String,
+}
+
+impl Attribute for Other {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{document_tree::o, science_lab::NodeExt, tags::Div};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_global_attributes() {
+        let result = Div
+            .hidden(Hidden::UntilFound)
+            .tabindex(-1)
+            .dir(Dir::Rtl)
+            .contenteditable(ContentEditable::PlaintextOnly)
+            .draggable(true)
+            .spellcheck(false)
+            .translate(Translate::No)
+            .accesskey('s')
+            .autocapitalize(Autocapitalize::Words)
+            .enterkeyhint(EnterKeyHint::Send)
+            .inputmode(InputMode::Numeric)
+            .popover(Popover::Manual)
+            .slot("title")
+            .part("label icon")
+            .render_string()
+            .unwrap();
+
+        let expected = concat!(
+            r#"<div hidden="until-found" tabindex="-1" dir="rtl" contenteditable="plaintext-only" "#,
+            r#"draggable="true" spellcheck="false" translate="no" accesskey="s" "#,
+            r#"autocapitalize="words" enterkeyhint="send" inputmode="numeric" popover="manual" "#,
+            r#"slot="title" part="label icon"></div>"#
+        );
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn render_boolean_global_attributes() {
+        let result = Div
+            .hidden(Hidden::Hidden)
+            .autofocus()
+            .inert()
+            .nonce("abc123")
+            .render_string()
+            .unwrap();
+
+        let expected = r#"<div hidden="" autofocus="" inert="" nonce="abc123"></div>"#;
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn escape_accesskey() {
+        assert_eq!(
+            r#"<div accesskey="&quot; a"></div>"#,
+            o(Div)
+                .add_attr(AccessKey::alternatives(&['"', 'a']))
+                .render_string()
+                .unwrap()
+        );
+    }
+}
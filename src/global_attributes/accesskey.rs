@@ -0,0 +1,36 @@
+use super::{escape_attribute_value, Attribute};
+
+/// Keyboard shortcut hint for the element.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/accesskey).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessKey(Vec<char>);
+
+impl AccessKey {
+    #[must_use]
+    pub fn new(key: char) -> Self {
+        Self(vec![key])
+    }
+
+    /// Several keys, the browser uses the first one it supports.
+    #[must_use]
+    pub fn alternatives(keys: &[char]) -> Self {
+        Self(keys.to_vec())
+    }
+}
+
+impl Attribute for AccessKey {
+    fn name(&self) -> &'static str {
+        "accesskey"
+    }
+
+    fn value(&self) -> String {
+        let keys = self
+            .0
+            .iter()
+            .map(char::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        escape_attribute_value(&keys)
+    }
+}
@@ -0,0 +1,416 @@
+use super::{escape_attribute_value, Attribute, Id};
+
+/// A tristate value, e.g. for [`Aria::Checked`] and [`Aria::Pressed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tristate {
+    True,
+    False,
+    Mixed,
+}
+
+impl Tristate {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+            Self::Mixed => "mixed",
+        }
+    }
+}
+
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// Values for [`Aria::Autocomplete`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Autocomplete {
+    Inline,
+    List,
+    Both,
+    None,
+}
+
+impl Autocomplete {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Inline => "inline",
+            Self::List => "list",
+            Self::Both => "both",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Values for [`Aria::Current`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Current {
+    Page,
+    Step,
+    Location,
+    Date,
+    Time,
+    True,
+    False,
+}
+
+impl Current {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Page => "page",
+            Self::Step => "step",
+            Self::Location => "location",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::True => "true",
+            Self::False => "false",
+        }
+    }
+}
+
+/// Values for [`Aria::HasPopup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HasPopup {
+    False,
+    True,
+    Menu,
+    Listbox,
+    Tree,
+    Grid,
+    Dialog,
+}
+
+impl HasPopup {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::False => "false",
+            Self::True => "true",
+            Self::Menu => "menu",
+            Self::Listbox => "listbox",
+            Self::Tree => "tree",
+            Self::Grid => "grid",
+            Self::Dialog => "dialog",
+        }
+    }
+}
+
+/// Values for [`Aria::Invalid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Invalid {
+    False,
+    True,
+    Grammar,
+    Spelling,
+}
+
+impl Invalid {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::False => "false",
+            Self::True => "true",
+            Self::Grammar => "grammar",
+            Self::Spelling => "spelling",
+        }
+    }
+}
+
+/// Values for [`Aria::Live`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Live {
+    Off,
+    Polite,
+    Assertive,
+}
+
+impl Live {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Polite => "polite",
+            Self::Assertive => "assertive",
+        }
+    }
+}
+
+/// Values for [`Aria::Orientation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+    Undefined,
+}
+
+impl Orientation {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+            Self::Undefined => "undefined",
+        }
+    }
+}
+
+/// Tokens for [`Aria::Relevant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relevant {
+    Additions,
+    Removals,
+    Text,
+    All,
+}
+
+impl Relevant {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Additions => "additions",
+            Self::Removals => "removals",
+            Self::Text => "text",
+            Self::All => "all",
+        }
+    }
+}
+
+/// Values for [`Aria::Sort`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    Ascending,
+    Descending,
+    None,
+    Other,
+}
+
+impl Sort {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+            Self::None => "none",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// ARIA states and properties, i.e. `aria-*`.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes).
+///
+/// Each variant carries a value of the type the attribute expects,
+/// for example id references for [`Aria::LabelledBy`] and a tristate for [`Aria::Checked`].
+/// The deprecated `aria-dropeffect` and `aria-grabbed` are left out.
+#[derive(Debug, Clone)]
+pub enum Aria {
+    ActiveDescendant(Id),
+    Atomic(bool),
+    Autocomplete(Autocomplete),
+    BrailleLabel(String),
+    BrailleRoleDescription(String),
+    Busy(bool),
+    Checked(Tristate),
+    ColCount(i64),
+    ColIndex(usize),
+    ColIndexText(String),
+    ColSpan(usize),
+    Controls(Vec<Id>),
+    Current(Current),
+    DescribedBy(Vec<Id>),
+    Description(String),
+    Details(Vec<Id>),
+    Disabled(bool),
+    ErrorMessage(Vec<Id>),
+    Expanded(bool),
+    FlowTo(Vec<Id>),
+    HasPopup(HasPopup),
+    Hidden(bool),
+    Invalid(Invalid),
+    KeyShortcuts(String),
+    Label(String),
+    LabelledBy(Vec<Id>),
+    Level(usize),
+    Live(Live),
+    Modal(bool),
+    Multiline(bool),
+    Multiselectable(bool),
+    Orientation(Orientation),
+    Owns(Vec<Id>),
+    Placeholder(String),
+    PosInSet(usize),
+    Pressed(Tristate),
+    ReadOnly(bool),
+    Relevant(Vec<Relevant>),
+    Required(bool),
+    RoleDescription(String),
+    RowCount(i64),
+    RowIndex(usize),
+    RowIndexText(String),
+    RowSpan(usize),
+    Selected(bool),
+    SetSize(i64),
+    Sort(Sort),
+    ValueMax(f64),
+    ValueMin(f64),
+    ValueNow(f64),
+    ValueText(String),
+}
+
+fn id_refs(ids: &[Id]) -> String {
+    ids.iter().map(Attribute::value).collect::<Vec<_>>().join(" ")
+}
+
+impl Attribute for Aria {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ActiveDescendant(_) => "aria-activedescendant",
+            Self::Atomic(_) => "aria-atomic",
+            Self::Autocomplete(_) => "aria-autocomplete",
+            Self::BrailleLabel(_) => "aria-braillelabel",
+            Self::BrailleRoleDescription(_) => "aria-brailleroledescription",
+            Self::Busy(_) => "aria-busy",
+            Self::Checked(_) => "aria-checked",
+            Self::ColCount(_) => "aria-colcount",
+            Self::ColIndex(_) => "aria-colindex",
+            Self::ColIndexText(_) => "aria-colindextext",
+            Self::ColSpan(_) => "aria-colspan",
+            Self::Controls(_) => "aria-controls",
+            Self::Current(_) => "aria-current",
+            Self::DescribedBy(_) => "aria-describedby",
+            Self::Description(_) => "aria-description",
+            Self::Details(_) => "aria-details",
+            Self::Disabled(_) => "aria-disabled",
+            Self::ErrorMessage(_) => "aria-errormessage",
+            Self::Expanded(_) => "aria-expanded",
+            Self::FlowTo(_) => "aria-flowto",
+            Self::HasPopup(_) => "aria-haspopup",
+            Self::Hidden(_) => "aria-hidden",
+            Self::Invalid(_) => "aria-invalid",
+            Self::KeyShortcuts(_) => "aria-keyshortcuts",
+            Self::Label(_) => "aria-label",
+            Self::LabelledBy(_) => "aria-labelledby",
+            Self::Level(_) => "aria-level",
+            Self::Live(_) => "aria-live",
+            Self::Modal(_) => "aria-modal",
+            Self::Multiline(_) => "aria-multiline",
+            Self::Multiselectable(_) => "aria-multiselectable",
+            Self::Orientation(_) => "aria-orientation",
+            Self::Owns(_) => "aria-owns",
+            Self::Placeholder(_) => "aria-placeholder",
+            Self::PosInSet(_) => "aria-posinset",
+            Self::Pressed(_) => "aria-pressed",
+            Self::ReadOnly(_) => "aria-readonly",
+            Self::Relevant(_) => "aria-relevant",
+            Self::Required(_) => "aria-required",
+            Self::RoleDescription(_) => "aria-roledescription",
+            Self::RowCount(_) => "aria-rowcount",
+            Self::RowIndex(_) => "aria-rowindex",
+            Self::RowIndexText(_) => "aria-rowindextext",
+            Self::RowSpan(_) => "aria-rowspan",
+            Self::Selected(_) => "aria-selected",
+            Self::SetSize(_) => "aria-setsize",
+            Self::Sort(_) => "aria-sort",
+            Self::ValueMax(_) => "aria-valuemax",
+            Self::ValueMin(_) => "aria-valuemin",
+            Self::ValueNow(_) => "aria-valuenow",
+            Self::ValueText(_) => "aria-valuetext",
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Atomic(b)
+            | Self::Busy(b)
+            | Self::Disabled(b)
+            | Self::Expanded(b)
+            | Self::Hidden(b)
+            | Self::Modal(b)
+            | Self::Multiline(b)
+            | Self::Multiselectable(b)
+            | Self::ReadOnly(b)
+            | Self::Required(b)
+            | Self::Selected(b) => b.to_string(),
+
+            Self::Checked(t) | Self::Pressed(t) => t.as_str().into(),
+
+            Self::ColCount(n) | Self::RowCount(n) | Self::SetSize(n) => n.to_string(),
+
+            Self::ColIndex(n)
+            | Self::ColSpan(n)
+            | Self::Level(n)
+            | Self::PosInSet(n)
+            | Self::RowIndex(n)
+            | Self::RowSpan(n) => n.to_string(),
+
+            Self::ValueMax(n) | Self::ValueMin(n) | Self::ValueNow(n) => n.to_string(),
+
+            Self::BrailleLabel(s)
+            | Self::BrailleRoleDescription(s)
+            | Self::ColIndexText(s)
+            | Self::Description(s)
+            | Self::KeyShortcuts(s)
+            | Self::Label(s)
+            | Self::Placeholder(s)
+            | Self::RoleDescription(s)
+            | Self::RowIndexText(s)
+            | Self::ValueText(s) => escape_attribute_value(s),
+
+            Self::ActiveDescendant(id) => id.value(),
+
+            Self::Controls(ids)
+            | Self::DescribedBy(ids)
+            | Self::Details(ids)
+            | Self::ErrorMessage(ids)
+            | Self::FlowTo(ids)
+            | Self::LabelledBy(ids)
+            | Self::Owns(ids) => id_refs(ids),
+
+            Self::Autocomplete(a) => a.as_str().into(),
+            Self::Current(c) => c.as_str().into(),
+            Self::HasPopup(h) => h.as_str().into(),
+            Self::Invalid(i) => i.as_str().into(),
+            Self::Live(l) => l.as_str().into(),
+            Self::Orientation(o) => o.as_str().into(),
+            Self::Relevant(tokens) => tokens
+                .iter()
+                .map(|r| r.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+            Self::Sort(s) => s.as_str().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{science_lab::NodeExt, tags::Button};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_aria() {
+        let result = Button::new()
+            .aria(Aria::Expanded(false))
+            .aria(Aria::Controls(vec![Id::new("menu"), Id::new("submenu")]))
+            .aria(Aria::Pressed(Tristate::Mixed))
+            .aria(Aria::Label("Open \"menu\"".into()))
+            .render_string()
+            .unwrap();
+
+        let expected = r#"<button aria-expanded="false" aria-controls="menu submenu" aria-pressed="mixed" aria-label="Open &quot;menu&quot;" type="submit"></button>"#;
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn render_aria_numbers() {
+        assert_eq!("3", Aria::ValueNow(3.0).value());
+        assert_eq!("0.5", Aria::ValueNow(0.5).value());
+        assert_eq!("-1", Aria::RowCount(-1).value());
+        assert_eq!(
+            "additions text",
+            Aria::Relevant(vec![Relevant::Additions, Relevant::Text]).value()
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use super::Attribute;
+
+/// How text input is automatically capitalized.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autocapitalize).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Autocapitalize {
+    Off,
+    Sentences,
+    Words,
+    Characters,
+}
+
+impl Attribute for Autocapitalize {
+    fn name(&self) -> &'static str {
+        "autocapitalize"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Off => "off",
+            Self::Sentences => "sentences",
+            Self::Words => "words",
+            Self::Characters => "characters",
+        }
+        .into()
+    }
+}
@@ -0,0 +1,16 @@
+use super::Attribute;
+
+/// Focus the element when the page loads or its dialog/popover is shown.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autofocus).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Autofocus;
+
+impl Attribute for Autofocus {
+    fn name(&self) -> &'static str {
+        "autofocus"
+    }
+
+    fn value(&self) -> String {
+        String::new()
+    }
+}
@@ -1,51 +1,139 @@
-// use super::Attribute;
+use std::fmt;
 
-pub type Class = String;
+/// A class name was empty or contained whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidClass(String);
 
-// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-// pub struct Class(String);
+impl fmt::Display for InvalidClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid class `{}`: must be non-empty without whitespace",
+            self.0
+        )
+    }
+}
 
-// impl Class {
-//     #[must_use]
-//     pub fn new(value: &str) -> Self {
-//         Self(value.into())
-//     }
-// }
+impl std::error::Error for InvalidClass {}
 
-// impl Attribute for Class {
-//     fn name(&self) -> &'static str {
-//         "class"
-//     }
+/// A single class name.
+///
+/// Guaranteed to be non-empty and without whitespace,
+/// such that a list of them always renders as a well-formed `class` attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Class(String);
 
-//     fn value(&self) -> String {
-//         self.0.clone()
-//     }
-// }
+impl Class {
+    /// Create a class name.
+    ///
+    /// # Errors
+    ///
+    /// If the class is empty or contains whitespace.
+    /// Use [`Class::split`] for whitespace separated lists.
+    pub fn new(class: &str) -> Result<Self, InvalidClass> {
+        if class.is_empty() || class.chars().any(char::is_whitespace) {
+            Err(InvalidClass(class.to_string()))
+        } else {
+            Ok(Self(class.to_string()))
+        }
+    }
 
-// #[cfg(test)]
-// mod tests {
-//     use crate::{document_tree::o, tags::Div};
-//     use pretty_assertions::assert_eq;
+    /// Split a whitespace separated list of class names, e.g. `"card card-1"`.
+    /// Empty input gives no classes.
+    pub fn split(classes: &str) -> impl Iterator<Item = Self> + '_ {
+        classes
+            .split_whitespace()
+            .map(|class| Self(class.to_string()))
+    }
 
-//     #[test]
-//     fn several_classes() {
-//         let div = o(Div)
-//             .add_text("Some div")
-//             .add_class("class-1")
-//             .add_class("class-2");
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
 
-//         let expected = r#"<div class="class-1" class="class-2">Some div</div>"#;
-//         let result = div.render_string().unwrap();
+impl AsRef<str> for Class {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
 
-//         // TODO: We don't want this.
-//         assert_eq!(expected, result);
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-//         // TODO: We can do this, but this is more of a "set class string" than "add class".
-//         let div = o(Div).add_text("Some div").add_class("class-1 class-2");
+#[cfg(test)]
+mod tests {
+    use crate::{document_tree::o, science_lab::NodeExt, tags::Div};
+    use pretty_assertions::assert_eq;
 
-//         let expected = r#"<div class="class-1 class-2">Some div</div>"#;
-//         let result = div.render_string().unwrap();
+    use super::*;
 
-//         assert_eq!(expected, result);
-//     }
-// }
+    #[test]
+    fn several_classes() {
+        let div = o(Div)
+            .add_text("Some div")
+            .add_class("class-1")
+            .add_class("class-2");
+
+        let expected = r#"<div class="class-1 class-2">Some div</div>"#;
+        let result = div.render_string().unwrap();
+
+        assert_eq!(expected, result);
+
+        let div = o(Div)
+            .add_text("Some div")
+            .add_class("class-1 class-2 class-1");
+
+        let result = div.render_string().unwrap();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(Class::new("").is_err());
+        assert!(Class::new("a b").is_err());
+        assert!(Class::new("a\tb").is_err());
+        assert_eq!("selected", Class::new("selected").unwrap().as_str());
+        assert_eq!(0, Class::split(" \n ").count());
+    }
+
+    #[test]
+    fn remove_toggle_has() {
+        let div = Div
+            .class("nav active")
+            .remove_class("nav")
+            .toggle_class("open")
+            .toggle_class("active");
+
+        assert!(div.has_class("open"));
+        assert!(!div.has_class("active"));
+        assert!(!div.has_class(""));
+        assert_eq!(vec!["open"], div.classes().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn conditional_classes() {
+        let (is_selected, is_disabled) = (true, false);
+
+        let result = Div
+            .class("item")
+            .class_if(is_selected, "selected")
+            .class_if(is_disabled, "disabled")
+            .classes_if([("first", true), ("last", false), ("", true), ("  ", true)])
+            .render_string()
+            .unwrap();
+
+        assert_eq!(r#"<div class="item selected first"></div>"#, result);
+    }
+
+    #[test]
+    fn no_empty_class_attribute() {
+        let result = Div.class("").class("   ").render_string().unwrap();
+
+        assert_eq!("<div></div>", result);
+    }
+}
@@ -0,0 +1,25 @@
+use super::Attribute;
+
+/// Whether the element is editable by the user.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/contenteditable).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEditable {
+    True,
+    False,
+    PlaintextOnly,
+}
+
+impl Attribute for ContentEditable {
+    fn name(&self) -> &'static str {
+        "contenteditable"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+            Self::PlaintextOnly => "plaintext-only",
+        }
+        .into()
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use super::{escape_attribute_value, Attribute};
+
+/// The name given to a [`Data`] attribute was not a valid custom data attribute name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDataName(String);
+
+impl fmt::Display for InvalidDataName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid data attribute name `{}`: expected lowercase letters, digits, `-`, `_` or `.`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidDataName {}
+
+/// A custom data attribute, i.e. `data-*`.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/data-*).
+///
+/// The name is given without the `data-` prefix,
+/// so `Data::new("story-id", "123")` renders as `data-story-id="123"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    name: String,
+    value: String,
+}
+
+impl Data {
+    /// Create a data attribute.
+    ///
+    /// # Panics
+    ///
+    /// If the name is not a valid data attribute name, see [`Data::try_new`].
+    #[must_use]
+    pub fn new(name: &str, value: &str) -> Self {
+        Self::try_new(name, value).expect("Must be a valid data attribute name")
+    }
+
+    /// Create a data attribute.
+    ///
+    /// # Errors
+    ///
+    /// The name must be non-empty and may only contain lowercase ASCII letters,
+    /// digits, `-`, `_` and `.`.
+    /// Uppercase letters are not allowed since browsers lowercase attribute names.
+    pub fn try_new(name: &str, value: &str) -> Result<Self, InvalidDataName> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '-' | '_' | '.'));
+
+        if valid {
+            Ok(Self {
+                name: format!("data-{name}"),
+                value: value.to_string(),
+            })
+        } else {
+            Err(InvalidDataName(name.to_string()))
+        }
+    }
+}
+
+impl Attribute for Data {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{document_tree::o, science_lab::NodeExt, tags::Div};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_data() {
+        let result = o(Div)
+            .add_attr(Data::new("story-id", "123"))
+            .render_string()
+            .unwrap();
+
+        assert_eq!(r#"<div data-story-id="123"></div>"#, result);
+    }
+
+    #[test]
+    fn render_data_builder_escapes_value() {
+        let result = Div.data("title", r#"Say "hi""#).render_string().unwrap();
+
+        assert_eq!(r#"<div data-title="Say &quot;hi&quot;"></div>"#, result);
+    }
+
+    #[test]
+    fn invalid_names() {
+        assert!(Data::try_new("", "value").is_err());
+        assert!(Data::try_new("storyId", "value").is_err());
+        assert!(Data::try_new("story id", "value").is_err());
+        assert!(Data::try_new("x:y", "value").is_err());
+        assert!(Data::try_new("story_id.2", "value").is_ok());
+    }
+}
@@ -0,0 +1,25 @@
+use super::Attribute;
+
+/// Text direction.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/dir).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dir {
+    Ltr,
+    Rtl,
+    Auto,
+}
+
+impl Attribute for Dir {
+    fn name(&self) -> &'static str {
+        "dir"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+            Self::Auto => "auto",
+        }
+        .into()
+    }
+}
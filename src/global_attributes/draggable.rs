@@ -0,0 +1,33 @@
+use super::Attribute;
+
+/// Whether the element can be dragged.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/draggable).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Draggable {
+    True,
+    False,
+}
+
+impl Attribute for Draggable {
+    fn name(&self) -> &'static str {
+        "draggable"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+        }
+        .into()
+    }
+}
+
+impl From<bool> for Draggable {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::Attribute;
+
+/// The action label to present for the enter key on virtual keyboards.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/enterkeyhint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnterKeyHint {
+    Enter,
+    Done,
+    Go,
+    Next,
+    Previous,
+    Search,
+    Send,
+}
+
+impl Attribute for EnterKeyHint {
+    fn name(&self) -> &'static str {
+        "enterkeyhint"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Enter => "enter",
+            Self::Done => "done",
+            Self::Go => "go",
+            Self::Next => "next",
+            Self::Previous => "previous",
+            Self::Search => "search",
+            Self::Send => "send",
+        }
+        .into()
+    }
+}
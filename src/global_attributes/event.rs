@@ -0,0 +1,252 @@
+use crate::hash::fnv1a;
+
+use super::{escape_attribute_value, Attribute};
+
+macro_rules! events {
+    ( $( $variant:ident => $event:literal ),* $(,)? ) => {
+        /// DOM events which can be handled, see [`On`] and [`Listener`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Event {
+            $( $variant, )*
+        }
+
+        impl Event {
+            /// The event's name, e.g. `click`.
+            #[must_use]
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $( Self::$variant => $event, )*
+                }
+            }
+
+            /// The inline handler attribute's name, e.g. `onclick`.
+            #[must_use]
+            pub const fn attribute_name(self) -> &'static str {
+                match self {
+                    $( Self::$variant => concat!("on", $event), )*
+                }
+            }
+        }
+    };
+}
+
+events![
+    Blur => "blur",
+    Change => "change",
+    Click => "click",
+    ContextMenu => "contextmenu",
+    DblClick => "dblclick",
+    Drag => "drag",
+    DragEnd => "dragend",
+    DragOver => "dragover",
+    DragStart => "dragstart",
+    Drop => "drop",
+    Error => "error",
+    Focus => "focus",
+    Input => "input",
+    Invalid => "invalid",
+    KeyDown => "keydown",
+    KeyUp => "keyup",
+    Load => "load",
+    MouseDown => "mousedown",
+    MouseEnter => "mouseenter",
+    MouseLeave => "mouseleave",
+    MouseOut => "mouseout",
+    MouseOver => "mouseover",
+    MouseUp => "mouseup",
+    PointerDown => "pointerdown",
+    PointerUp => "pointerup",
+    Reset => "reset",
+    Scroll => "scroll",
+    Select => "select",
+    Submit => "submit",
+    Toggle => "toggle",
+    TouchEnd => "touchend",
+    TouchStart => "touchstart",
+    Wheel => "wheel",
+];
+
+/// An inline event handler attribute, e.g. `onclick="..."`.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes#event_handler_attributes).
+///
+/// The script is escaped for the attribute context.
+/// Inline handlers are blocked by a strict Content Security Policy,
+/// see [`Listener`] for an alternative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct On {
+    event: Event,
+    script: String,
+}
+
+impl On {
+    #[must_use]
+    pub fn new(event: Event, script: &str) -> Self {
+        Self {
+            event,
+            script: script.to_string(),
+        }
+    }
+}
+
+impl Attribute for On {
+    fn name(&self) -> &'static str {
+        self.event.attribute_name()
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.script)
+    }
+}
+
+/// An event handler which works under a strict Content Security Policy.
+///
+/// Instead of an inline `on*` attribute, the element gets a `data-on-*` hook,
+/// and the handler is moved into a single `<script>` which attaches all listeners.
+/// [`crate::template::HtmlDocumentBuilder`] collects the listeners used in the body
+/// and adds that script at the end of it, with a nonce if one is configured.
+///
+/// Within the script, `this` is the element and `event` is the event,
+/// same as for inline handlers. Returning `false` cancels the event,
+/// e.g. `return confirm('Delete?')` only submits a form if confirmed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Listener {
+    event: Event,
+    hook: String,
+    script: String,
+}
+
+impl Listener {
+    /// The hook is derived from the event and script,
+    /// so identical handlers on several elements share one listener registration.
+    #[must_use]
+    pub fn new(event: Event, script: &str) -> Self {
+        let hook = format!("h{:08x}", fnv1a(&format!("{}:{script}", event.as_str())));
+
+        Self {
+            event,
+            hook,
+            script: script.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub const fn event(&self) -> Event {
+        self.event
+    }
+
+    /// The value of the `data-on-*` attribute identifying this handler.
+    #[must_use]
+    pub fn hook(&self) -> &str {
+        &self.hook
+    }
+
+    /// The hook attribute's name, e.g. `data-on-click`.
+    #[must_use]
+    pub fn hook_attribute(&self) -> String {
+        format!("data-on-{}", self.event.as_str())
+    }
+
+    /// Javascript attaching all given listeners to the elements carrying their hooks.
+    #[must_use]
+    pub fn script(listeners: &[Self]) -> String {
+        listeners
+            .iter()
+            .map(|listener| {
+                format!(
+                    "document.querySelectorAll('[{}~=\"{}\"]').forEach(function(el){{el.addEventListener('{}',function(event){{if((function(event){{{}}}).call(this,event)===false)event.preventDefault();}});}});",
+                    listener.hook_attribute(),
+                    listener.hook,
+                    listener.event.as_str(),
+                    // Do not let the handler end the script element early.
+                    listener.script.replace("</", "<\\/")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        science_lab::NodeExt,
+        tags::{Body, Button, Form, Head},
+        template::HtmlDocumentBuilder,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_inline_handler_escaped() {
+        let result = Button::new()
+            .on(Event::Click, r#"toggle("c1") && alert('<hi> & bye')"#)
+            .render_string()
+            .unwrap();
+
+        let expected = r#"<button onclick="toggle(&quot;c1&quot;) &amp;&amp; alert(&#39;&lt;hi&gt; &amp; bye&#39;)" type="submit"></button>"#;
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn render_listener_hooks() {
+        let toggle = Listener::new(Event::Click, "toggle(this)");
+        let track = Listener::new(Event::Click, "track(this)");
+
+        let result = Button::new()
+            .listen(Event::Click, "toggle(this)")
+            .listen(Event::Click, "track(this)")
+            .listen(Event::Focus, "track(this)")
+            .render_string()
+            .unwrap();
+
+        let expected = format!(
+            r#"<button data-on-click="{} {}" data-on-focus="{}" type="submit"></button>"#,
+            toggle.hook(),
+            track.hook(),
+            Listener::new(Event::Focus, "track(this)").hook()
+        );
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn document_collects_listeners_into_nonce_script() {
+        let toggle = Listener::new(Event::Click, "toggle(this)");
+
+        let body = Body
+            .kid(Button::new().listen(Event::Click, "toggle(this)"))
+            .kid(Button::new().listen(Event::Click, "toggle(this)"))
+            .kid(
+                o(Form::new(crate::tags::form::Method::Post, "/comment"))
+                    .listen(Event::Submit, "return confirm('</script>')"),
+            );
+
+        let result = HtmlDocumentBuilder::new()
+            .with_head(o(Head))
+            .with_body(body)
+            .with_nonce("r4nd0m")
+            .build()
+            .render_string()
+            .unwrap();
+
+        let hook = format!(r#"data-on-click="{}""#, toggle.hook());
+        assert_eq!(2, result.matches(&hook).count());
+
+        let expected_script = format!(
+            concat!(
+                r#"<script nonce="r4nd0m">"#,
+                r#"document.querySelectorAll('[data-on-click~="{}"]').forEach(function(el){{el.addEventListener('click',function(event){{if((function(event){{toggle(this)}}).call(this,event)===false)event.preventDefault();}});}});"#,
+                "\n",
+                r#"document.querySelectorAll('[data-on-submit~="{}"]').forEach(function(el){{el.addEventListener('submit',function(event){{if((function(event){{return confirm('<\/script>')}}).call(this,event)===false)event.preventDefault();}});}});"#,
+                "</script></body>"
+            ),
+            toggle.hook(),
+            Listener::new(Event::Submit, "return confirm('</script>')").hook()
+        );
+        assert!(
+            result.ends_with(&format!("{expected_script}</html>")),
+            "{result}"
+        );
+    }
+}
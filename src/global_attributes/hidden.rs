@@ -0,0 +1,25 @@
+use super::Attribute;
+
+/// Hide an element.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/hidden).
+///
+/// [`Hidden::UntilFound`] keeps the content searchable by find-in-page and fragment navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hidden {
+    Hidden,
+    UntilFound,
+}
+
+impl Attribute for Hidden {
+    fn name(&self) -> &'static str {
+        "hidden"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Hidden => "",
+            Self::UntilFound => "until-found",
+        }
+        .into()
+    }
+}
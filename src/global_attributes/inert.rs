@@ -0,0 +1,16 @@
+use super::Attribute;
+
+/// Make the element and its subtree non-interactive.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/inert).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inert;
+
+impl Attribute for Inert {
+    fn name(&self) -> &'static str {
+        "inert"
+    }
+
+    fn value(&self) -> String {
+        String::new()
+    }
+}
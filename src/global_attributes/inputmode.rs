@@ -0,0 +1,35 @@
+use super::Attribute;
+
+/// The kind of virtual keyboard to present.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/inputmode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    None,
+    Text,
+    Decimal,
+    Numeric,
+    Tel,
+    Search,
+    Email,
+    Url,
+}
+
+impl Attribute for InputMode {
+    fn name(&self) -> &'static str {
+        "inputmode"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::None => "none",
+            Self::Text => "text",
+            Self::Decimal => "decimal",
+            Self::Numeric => "numeric",
+            Self::Tel => "tel",
+            Self::Search => "search",
+            Self::Email => "email",
+            Self::Url => "url",
+        }
+        .into()
+    }
+}
@@ -1,10 +1,264 @@
-use super::Attribute;
+use std::{borrow::Cow, fmt, str::FromStr};
 
-#[derive(Debug, Clone)]
-pub enum Lang {
-    English,
-    French,
-    Spanish,
+use super::{Attribute, Dir};
+
+/// Why a language tag could not be parsed, see [`Lang`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseLangError {
+    /// The tag was empty.
+    Empty,
+    /// The primary language subtag is not 2-3 or 5-8 letters.
+    InvalidLanguage(String),
+    /// A subtag did not fit any of script, region or variant, in that order.
+    InvalidSubtag(String),
+    /// The same variant was given twice.
+    DuplicateVariant(String),
+}
+
+impl fmt::Display for ParseLangError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty language tag"),
+            Self::InvalidLanguage(language) => write!(f, "invalid language subtag `{language}`"),
+            Self::InvalidSubtag(subtag) => write!(f, "invalid or misplaced subtag `{subtag}`"),
+            Self::DuplicateVariant(variant) => write!(f, "duplicate variant subtag `{variant}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseLangError {}
+
+/// A [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/lang).
+///
+/// For example `en`, `nb-NO`, `zh-Hant-TW` or `sl-rozaj-biske`.
+/// Covers the language, script, region and variant subtags.
+/// Tags are parsed case-insensitively and rendered in their canonical casing,
+/// so `EN-latn-us` becomes `en-Latn-US`.
+///
+/// Extensions, private use and grandfathered tags are not supported.
+///
+/// Common languages are available as constants, e.g. [`Lang::ENGLISH`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lang {
+    language: Cow<'static, str>,
+    script: Option<Cow<'static, str>>,
+    region: Option<Cow<'static, str>>,
+    variants: Vec<String>,
+}
+
+/// Scripts written right-to-left.
+const RTL_SCRIPTS: &[&str] = &[
+    "Adlm", "Arab", "Hebr", "Mand", "Mend", "Nkoo", "Rohg", "Samr", "Syrc", "Thaa", "Yezi",
+];
+
+/// Languages written right-to-left when no script is given.
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "syr", "ug", "ur", "yi",
+];
+
+impl Lang {
+    pub const ARABIC: Self = Self::from_static("ar", None);
+    pub const CHINESE: Self = Self::from_static("zh", None);
+    pub const CHINESE_SIMPLIFIED: Self = Self::from_static("zh", Some("Hans"));
+    pub const CHINESE_TRADITIONAL: Self = Self::from_static("zh", Some("Hant"));
+    pub const DANISH: Self = Self::from_static("da", None);
+    pub const DUTCH: Self = Self::from_static("nl", None);
+    pub const ENGLISH: Self = Self::from_static("en", None);
+    pub const FINNISH: Self = Self::from_static("fi", None);
+    pub const FRENCH: Self = Self::from_static("fr", None);
+    pub const GERMAN: Self = Self::from_static("de", None);
+    pub const HEBREW: Self = Self::from_static("he", None);
+    pub const HINDI: Self = Self::from_static("hi", None);
+    pub const ITALIAN: Self = Self::from_static("it", None);
+    pub const JAPANESE: Self = Self::from_static("ja", None);
+    pub const KOREAN: Self = Self::from_static("ko", None);
+    pub const NORWEGIAN: Self = Self::from_static("no", None);
+    pub const NORWEGIAN_BOKMAL: Self = Self::from_static("nb", None);
+    pub const NORWEGIAN_NYNORSK: Self = Self::from_static("nn", None);
+    pub const PERSIAN: Self = Self::from_static("fa", None);
+    pub const POLISH: Self = Self::from_static("pl", None);
+    pub const PORTUGUESE: Self = Self::from_static("pt", None);
+    pub const RUSSIAN: Self = Self::from_static("ru", None);
+    pub const SPANISH: Self = Self::from_static("es", None);
+    pub const SWEDISH: Self = Self::from_static("sv", None);
+    pub const TURKISH: Self = Self::from_static("tr", None);
+    pub const UKRAINIAN: Self = Self::from_static("uk", None);
+    pub const URDU: Self = Self::from_static("ur", None);
+
+    const fn from_static(language: &'static str, script: Option<&'static str>) -> Self {
+        Self {
+            language: Cow::Borrowed(language),
+            script: match script {
+                Some(script) => Some(Cow::Borrowed(script)),
+                None => None,
+            },
+            region: None,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Parse a language tag.
+    ///
+    /// # Errors
+    ///
+    /// See [`ParseLangError`].
+    pub fn new(tag: &str) -> Result<Self, ParseLangError> {
+        tag.parse()
+    }
+
+    /// Add or replace the region, e.g. `NO` or `419`.
+    ///
+    /// # Errors
+    ///
+    /// If the region is neither two letters nor three digits.
+    pub fn with_region(mut self, region: &str) -> Result<Self, ParseLangError> {
+        if !is_region(region) {
+            return Err(ParseLangError::InvalidSubtag(region.to_string()));
+        }
+
+        self.region = Some(region.to_ascii_uppercase().into());
+        Ok(self)
+    }
+
+    /// The primary language subtag, e.g. `en`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The script subtag, e.g. `Latn`.
+    #[must_use]
+    pub fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    /// The region subtag, e.g. `US`.
+    #[must_use]
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// The variant subtags, e.g. `rozaj`.
+    #[must_use]
+    pub fn variants(&self) -> &[String] {
+        &self.variants
+    }
+
+    /// The direction text in this language is written in.
+    ///
+    /// An explicit script decides, e.g. `pa-Arab` is right-to-left while `sd-Deva` is not.
+    /// Otherwise the language's usual script decides.
+    #[must_use]
+    pub fn direction(&self) -> Dir {
+        let rtl = self.script.as_ref().map_or_else(
+            || RTL_LANGUAGES.contains(&self.language.as_ref()),
+            |script| RTL_SCRIPTS.contains(&script.as_ref()),
+        );
+
+        if rtl {
+            Dir::Rtl
+        } else {
+            Dir::Ltr
+        }
+    }
+}
+
+fn is_alpha(subtag: &str, lengths: std::ops::RangeInclusive<usize>) -> bool {
+    lengths.contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_region(subtag: &str) -> bool {
+    is_alpha(subtag, 2..=2) || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(subtag: &str) -> bool {
+    let alphanumeric = subtag.bytes().all(|b| b.is_ascii_alphanumeric());
+
+    match subtag.len() {
+        5..=8 => alphanumeric,
+        4 => alphanumeric && subtag.as_bytes()[0].is_ascii_digit(),
+        _ => false,
+    }
+}
+
+fn title_case(subtag: &str) -> String {
+    let lower = subtag.to_ascii_lowercase();
+    let (first, rest) = lower.split_at(1);
+
+    format!("{}{rest}", first.to_ascii_uppercase())
+}
+
+impl FromStr for Lang {
+    type Err = ParseLangError;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let mut subtags = tag.split(['-', '_']).peekable();
+
+        let language = match subtags.next() {
+            None | Some("") => return Err(ParseLangError::Empty),
+            Some(language) if is_alpha(language, 2..=3) || is_alpha(language, 5..=8) => {
+                language.to_ascii_lowercase()
+            }
+            Some(language) => return Err(ParseLangError::InvalidLanguage(language.to_string())),
+        };
+
+        let script = subtags
+            .next_if(|subtag| is_alpha(subtag, 4..=4))
+            .map(|script| Cow::Owned(title_case(script)));
+
+        let region = subtags
+            .next_if(|subtag| is_region(subtag))
+            .map(|region| Cow::Owned(region.to_ascii_uppercase()));
+
+        let mut variants: Vec<String> = vec![];
+        for subtag in subtags {
+            if !is_variant(subtag) {
+                return Err(ParseLangError::InvalidSubtag(subtag.to_string()));
+            }
+
+            let variant = subtag.to_ascii_lowercase();
+            if variants.contains(&variant) {
+                return Err(ParseLangError::DuplicateVariant(variant));
+            }
+            variants.push(variant);
+        }
+
+        Ok(Self {
+            language: Cow::Owned(language),
+            script,
+            region,
+            variants,
+        })
+    }
+}
+
+impl TryFrom<&str> for Lang {
+    type Error = ParseLangError;
+
+    fn try_from(tag: &str) -> Result<Self, Self::Error> {
+        tag.parse()
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.language)?;
+
+        if let Some(script) = &self.script {
+            write!(f, "-{script}")?;
+        }
+
+        if let Some(region) = &self.region {
+            write!(f, "-{region}")?;
+        }
+
+        for variant in &self.variants {
+            write!(f, "-{variant}")?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Attribute for Lang {
@@ -13,11 +267,104 @@ impl Attribute for Lang {
     }
 
     fn value(&self) -> String {
-        match self {
-            Lang::English => "en",
-            Lang::French => "fr",
-            Lang::Spanish => "es",
-        }
-        .into()
+        self.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{science_lab::NodeExt, tags::Html, template::HtmlDocumentBuilder};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_canonical_casing() {
+        let lang: Lang = "ZH-hant_tw".parse().unwrap();
+
+        assert_eq!("zh-Hant-TW", lang.to_string());
+        assert_eq!("zh", lang.language());
+        assert_eq!(Some("Hant"), lang.script());
+        assert_eq!(Some("TW"), lang.region());
+    }
+
+    #[test]
+    fn parse_variants() {
+        let lang = Lang::new("sl-IT-rozaj-biske-1994").unwrap();
+
+        assert_eq!(["rozaj", "biske", "1994"], lang.variants());
+        assert_eq!(
+            Err(ParseLangError::DuplicateVariant("rozaj".into())),
+            Lang::new("sl-rozaj-ROZAJ")
+        );
+    }
+
+    #[test]
+    fn parse_region_numeric() {
+        assert_eq!("es-419", Lang::new("es-419").unwrap().to_string());
+        assert_eq!(
+            "nb-NO",
+            Lang::NORWEGIAN_BOKMAL
+                .with_region("no")
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(ParseLangError::Empty), Lang::new(""));
+        assert_eq!(
+            Err(ParseLangError::InvalidLanguage("e".into())),
+            Lang::new("e")
+        );
+        assert_eq!(
+            Err(ParseLangError::InvalidSubtag("x".into())),
+            Lang::new("en-x")
+        );
+        assert_eq!(
+            Err(ParseLangError::InvalidSubtag("Latn".into())),
+            Lang::new("en-US-Latn")
+        );
+    }
+
+    #[test]
+    fn direction() {
+        assert_eq!(Dir::Rtl, Lang::ARABIC.direction());
+        assert_eq!(Dir::Rtl, Lang::new("he-IL").unwrap().direction());
+        assert_eq!(Dir::Rtl, Lang::new("pa-Arab").unwrap().direction());
+        assert_eq!(Dir::Ltr, Lang::new("sd-Deva").unwrap().direction());
+        assert_eq!(Dir::Ltr, Lang::JAPANESE.direction());
+    }
+
+    #[test]
+    fn render_lang_sets_dir_when_rtl() {
+        assert_eq!(
+            r#"<html lang="ja"></html>"#,
+            Html.lang(Lang::JAPANESE).render_string().unwrap()
+        );
+        assert_eq!(
+            r#"<html lang="ar" dir="rtl"></html>"#,
+            Html.lang(Lang::ARABIC).render_string().unwrap()
+        );
+        assert_eq!(
+            r#"<html lang="ar" dir="rtl"></html>"#,
+            Html.dir(Dir::Ltr)
+                .set_lang(Lang::JAPANESE)
+                .set_lang(Lang::ARABIC)
+                .render_string()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn render_document_with_lang() {
+        let result = HtmlDocumentBuilder::new()
+            .with_lang(Lang::HEBREW)
+            .build()
+            .render_string()
+            .unwrap();
+
+        assert!(result.contains(r#"<html lang="he" dir="rtl">"#));
     }
 }
@@ -0,0 +1,44 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use super::{escape_attribute_value, Attribute};
+
+/// A cryptographic nonce used by Content Security Policy.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/nonce).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nonce(String);
+
+impl Nonce {
+    #[must_use]
+    pub fn new(nonce: &str) -> Self {
+        Self(nonce.to_string())
+    }
+
+    /// A fresh nonce of 128 random bits, base64 encoded.
+    /// Use a new one for every response, see [`crate::csp::Csp`].
+    ///
+    /// # Panics
+    ///
+    /// If the operating system cannot provide randomness.
+    #[must_use]
+    pub fn generate() -> Self {
+        let mut bytes = [0; 16];
+        getrandom::getrandom(&mut bytes).expect("Should be able to get random bytes");
+
+        Self(STANDARD.encode(bytes))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Attribute for Nonce {
+    fn name(&self) -> &'static str {
+        "nonce"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
@@ -0,0 +1,24 @@
+use super::{escape_attribute_value, Attribute};
+
+/// Part names exposing the element for styling via `::part()` from outside a shadow tree.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/part).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part(Vec<String>);
+
+impl Part {
+    /// Whitespace separates several part names.
+    #[must_use]
+    pub fn new(parts: &str) -> Self {
+        Self(parts.split_ascii_whitespace().map(String::from).collect())
+    }
+}
+
+impl Attribute for Part {
+    fn name(&self) -> &'static str {
+        "part"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0.join(" "))
+    }
+}
@@ -0,0 +1,25 @@
+use super::Attribute;
+
+/// Turn the element into a popover.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/popover).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Popover {
+    Auto,
+    Manual,
+    Hint,
+}
+
+impl Attribute for Popover {
+    fn name(&self) -> &'static str {
+        "popover"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Auto => "auto",
+            Self::Manual => "manual",
+            Self::Hint => "hint",
+        }
+        .into()
+    }
+}
@@ -0,0 +1,23 @@
+use super::{escape_attribute_value, Attribute};
+
+/// Assigns the element to the named slot of a shadow tree.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/slot).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slot(String);
+
+impl Slot {
+    #[must_use]
+    pub fn new(slot: &str) -> Self {
+        Self(slot.to_string())
+    }
+}
+
+impl Attribute for Slot {
+    fn name(&self) -> &'static str {
+        "slot"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
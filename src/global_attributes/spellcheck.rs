@@ -0,0 +1,33 @@
+use super::Attribute;
+
+/// Whether the element may be checked for spelling errors.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/spellcheck).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spellcheck {
+    True,
+    False,
+}
+
+impl Attribute for Spellcheck {
+    fn name(&self) -> &'static str {
+        "spellcheck"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+        }
+        .into()
+    }
+}
+
+impl From<bool> for Spellcheck {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
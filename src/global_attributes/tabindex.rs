@@ -0,0 +1,18 @@
+use super::Attribute;
+
+/// Whether and in which order the element takes part in sequential keyboard navigation.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex).
+///
+/// A negative value makes the element focusable but not reachable via sequential navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tabindex(pub i32);
+
+impl Attribute for Tabindex {
+    fn name(&self) -> &'static str {
+        "tabindex"
+    }
+
+    fn value(&self) -> String {
+        self.0.to_string()
+    }
+}
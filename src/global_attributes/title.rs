@@ -1,4 +1,4 @@
-use super::Attribute;
+use super::{escape_attribute_value, Attribute};
 
 #[derive(Debug, Clone)]
 pub struct Title(String);
@@ -16,6 +16,6 @@ impl Attribute for Title {
     }
 
     fn value(&self) -> String {
-        self.0.clone()
+        escape_attribute_value(&self.0)
     }
 }
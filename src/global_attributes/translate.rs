@@ -0,0 +1,33 @@
+use super::Attribute;
+
+/// Whether the element should be translated when the page is localized.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/translate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Translate {
+    Yes,
+    No,
+}
+
+impl Attribute for Translate {
+    fn name(&self) -> &'static str {
+        "translate"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Yes => "yes",
+            Self::No => "no",
+        }
+        .into()
+    }
+}
+
+impl From<bool> for Translate {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Yes
+        } else {
+            Self::No
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// FNV-1a, chosen since it is stable across Rust versions and platforms.
+///
+/// Used for short, deterministic identifiers, not for anything security related.
+pub fn fnv1a(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
@@ -0,0 +1,626 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "serde")]
+use crate::data_table::{field_text, Fields};
+use crate::{
+    attributes::href::SafeUrl,
+    document_tree::Node,
+    global_attributes::{
+        aria::{Aria, Invalid},
+        escape_attribute_value, Id,
+    },
+    science_lab::NodeExt,
+    tags::{
+        form::Method,
+        input::{kind, Autofill},
+        Div, Form, Input, Label, P,
+    },
+};
+
+/// A struct which may be edited through an HTML form, see [`FormBuilder`].
+///
+/// The fields should match what the handler deserializes, such that the names line up.
+/// With the `serde` feature, `FormBuilder::values` fills in the form from a value
+/// serialized the same way.
+///
+/// ```
+/// use html_strong::{
+///     html_form::{Field, FieldKind, FormBuilder, HtmlForm},
+///     tags::form::Method,
+/// };
+///
+/// struct Signup {
+///     email: String,
+///     age: Option<u8>,
+/// }
+///
+/// impl HtmlForm for Signup {
+///     fn fields() -> Vec<Field> {
+///         vec![
+///             Field::new::<String>("email", "Email").kind(FieldKind::Email),
+///             Field::new::<Option<u8>>("age", "Age").min(13),
+///         ]
+///     }
+/// }
+///
+/// let form = FormBuilder::<Signup>::new(Method::Post, "/signup")
+///     .submitted([("email", "nope"), ("age", "")])
+///     .error("email", "Not an email address")
+///     .build();
+///
+/// assert!(form.render_string().unwrap().contains(r#"aria-invalid="true""#));
+/// ```
+pub trait HtmlForm {
+    /// The fields of the form, in order.
+    fn fields() -> Vec<Field>;
+}
+
+/// How a Rust type is edited, see [`Field::new`].
+pub trait FieldValue {
+    const KIND: FieldKind;
+
+    /// If a value must be given, which is not the case for e.g. [`Option`].
+    const REQUIRED: bool = true;
+}
+
+macro_rules! field_values {
+    ( $kind:ident: $( $type:ty ),* ) => {
+        $(
+            impl FieldValue for $type {
+                const KIND: FieldKind = FieldKind::$kind;
+            }
+        )*
+    };
+    ( $kind:ident, optional: $type:ty ) => {
+        impl FieldValue for $type {
+            const KIND: FieldKind = FieldKind::$kind;
+            const REQUIRED: bool = false;
+        }
+    };
+}
+
+field_values!(Text: String, &str, char);
+field_values!(Integer: i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+field_values!(Decimal: f32, f64);
+field_values!(Url: url::Url);
+field_values!(Checkbox, optional: bool);
+
+impl<T: FieldValue> FieldValue for Option<T> {
+    const KIND: FieldKind = T::KIND;
+    const REQUIRED: bool = false;
+}
+
+/// The input used for a [`Field`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    Search,
+    Url,
+    Tel,
+    Email,
+    Password,
+    /// A number input accepting whole numbers.
+    Integer,
+    /// A number input accepting any number.
+    Decimal,
+    Date,
+    Time,
+    DatetimeLocal,
+    Month,
+    Week,
+    Color,
+    /// A checkbox, submitting `true` when checked.
+    /// The handler should default to `false`, e.g. with `#[serde(default)]`.
+    Checkbox,
+    Hidden,
+}
+
+/// A bound given to [`Field::min`] or [`Field::max`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bound {
+    /// For [`FieldKind::Integer`] and [`FieldKind::Decimal`].
+    Number(f64),
+    /// For dates and times, e.g. `2022-04-01`.
+    Text(String),
+}
+
+macro_rules! number_bounds {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl From<$type> for Bound {
+                fn from(number: $type) -> Self {
+                    Self::Number(number.into())
+                }
+            }
+        )*
+    };
+}
+
+number_bounds!(i8, i16, i32, u8, u16, u32, f32, f64);
+
+impl From<&str> for Bound {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// A field of an [`HtmlForm`], rendered as a label and an input.
+#[derive(Debug, Clone)]
+pub struct Field {
+    name: &'static str,
+    label: String,
+    kind: FieldKind,
+    required: bool,
+    placeholder: Option<String>,
+    minlength: Option<usize>,
+    maxlength: Option<usize>,
+    pattern: Option<String>,
+    min: Option<Bound>,
+    max: Option<Bound>,
+    autocomplete: Option<Autofill>,
+}
+
+impl Field {
+    /// A field for a value of type `T`, which decides the input type and whether it is required.
+    #[must_use]
+    pub fn new<T: FieldValue>(name: &'static str, label: &str) -> Self {
+        Self {
+            name,
+            label: label.to_string(),
+            kind: T::KIND,
+            required: T::REQUIRED,
+            placeholder: None,
+            minlength: None,
+            maxlength: None,
+            pattern: None,
+            min: None,
+            max: None,
+            autocomplete: None,
+        }
+    }
+
+    /// Use another input type, e.g. [`FieldKind::Email`] for a [`String`].
+    #[must_use]
+    pub const fn kind(mut self, kind: FieldKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    #[must_use]
+    pub const fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Only used by text and number inputs.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Only used by text inputs.
+    #[must_use]
+    pub const fn minlength(mut self, length: usize) -> Self {
+        self.minlength = Some(length);
+        self
+    }
+
+    /// Only used by text inputs.
+    #[must_use]
+    pub const fn maxlength(mut self, length: usize) -> Self {
+        self.maxlength = Some(length);
+        self
+    }
+
+    /// Only used by text inputs.
+    #[must_use]
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Only used by number, date and time inputs, given a bound of the matching kind.
+    #[must_use]
+    pub fn min(mut self, min: impl Into<Bound>) -> Self {
+        self.min = Some(min.into());
+        self
+    }
+
+    /// Only used by number, date and time inputs, given a bound of the matching kind.
+    #[must_use]
+    pub fn max(mut self, max: impl Into<Bound>) -> Self {
+        self.max = Some(max.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn autocomplete(mut self, autofill: Autofill) -> Self {
+        self.autocomplete = Some(autofill);
+        self
+    }
+
+    fn text_like<K>(&self, mut input: Input<K>) -> Input<K>
+    where
+        K: kind::TextLike + kind::Placeholder + kind::Required + kind::Autocomplete,
+    {
+        if let Some(length) = self.minlength {
+            input = input.minlength(length);
+        }
+        if let Some(length) = self.maxlength {
+            input = input.maxlength(length);
+        }
+        if let Some(pattern) = &self.pattern {
+            input = input.pattern(pattern);
+        }
+        if let Some(placeholder) = &self.placeholder {
+            input = input.placeholder(placeholder);
+        }
+        self.common(input)
+    }
+
+    fn number<K>(&self, mut input: Input<K>) -> Input<K>
+    where
+        K: kind::Ranged<Bound = f64> + kind::Placeholder + kind::Required + kind::Autocomplete,
+    {
+        if let Some(Bound::Number(min)) = self.min {
+            input = input.min(min);
+        }
+        if let Some(Bound::Number(max)) = self.max {
+            input = input.max(max);
+        }
+        if let Some(placeholder) = &self.placeholder {
+            input = input.placeholder(placeholder);
+        }
+        self.common(input)
+    }
+
+    fn date<K>(&self, mut input: Input<K>) -> Input<K>
+    where
+        K: kind::Ranged<Bound = String> + kind::Required + kind::Autocomplete,
+    {
+        if let Some(Bound::Text(min)) = &self.min {
+            input = input.min(min.as_str());
+        }
+        if let Some(Bound::Text(max)) = &self.max {
+            input = input.max(max.as_str());
+        }
+        self.common(input)
+    }
+
+    fn common<K>(&self, mut input: Input<K>) -> Input<K>
+    where
+        K: kind::Required + kind::Autocomplete,
+    {
+        if self.required {
+            input = input.required();
+        }
+        if let Some(autofill) = self.autocomplete {
+            input = input.autocomplete(autofill);
+        }
+        input
+    }
+
+    /// The input, filled in with the given value.
+    fn input(&self, value: &str) -> Node {
+        let name = self.name;
+
+        match self.kind {
+            FieldKind::Text => self.text_like(Input::text(name, value)).into_node(),
+            FieldKind::Search => self.text_like(Input::search(name).value(value)).into_node(),
+            FieldKind::Url => self.text_like(Input::url(name).value(value)).into_node(),
+            FieldKind::Tel => self.text_like(Input::tel(name).value(value)).into_node(),
+            FieldKind::Email => self.text_like(Input::email(name).value(value)).into_node(),
+            // Never sent back to the browser, the user has to type it again.
+            FieldKind::Password => self.text_like(Input::password(name)).into_node(),
+            FieldKind::Integer => self.number(Input::number(name).value(value)).into_node(),
+            FieldKind::Decimal => self
+                .number(Input::number(name).value(value).step_any())
+                .into_node(),
+            FieldKind::Date => self.date(Input::date(name).value(value)).into_node(),
+            FieldKind::Time => self.date(Input::time(name).value(value)).into_node(),
+            FieldKind::DatetimeLocal => self
+                .date(Input::datetime_local(name).value(value))
+                .into_node(),
+            FieldKind::Month => self.date(Input::month(name).value(value)).into_node(),
+            FieldKind::Week => self.date(Input::week(name).value(value)).into_node(),
+            FieldKind::Color => Input::color(name).value(value).into_node(),
+            FieldKind::Checkbox => {
+                let checked = matches!(value, "true" | "on");
+                let input = Input::checkbox(name, "true").checked(checked);
+
+                if self.required {
+                    input.required().into_node()
+                } else {
+                    input.into_node()
+                }
+            }
+            FieldKind::Hidden => Input::hidden(name, value).into_node(),
+        }
+    }
+
+    /// The label, input and errors.
+    /// Hidden fields are only the input, their errors are shown for the form instead.
+    fn render(&self, value: &str, errors: &[&str]) -> Node {
+        let mut input = self.input(value).set_id(self.name);
+
+        if self.kind == FieldKind::Hidden {
+            return input;
+        }
+
+        let error_id = format!("{}-error", self.name);
+        if !errors.is_empty() {
+            input = input
+                .add_attr(Aria::Invalid(Invalid::True))
+                .add_attr(Aria::DescribedBy(vec![Id::new(&error_id)]));
+        }
+
+        let label = Label::new(self.name).text(escape_attribute_value(&self.label));
+        let mut field = Div.class("field").kid(label).kid(input);
+
+        if !errors.is_empty() {
+            let error = P
+                .class("field-error")
+                .set_id(&error_id)
+                .text(escape_attribute_value(&errors.join(" ")));
+            field = field.kid(error);
+        }
+
+        field
+    }
+}
+
+/// Builds the form of an [`HtmlForm`], see its example.
+///
+/// After a failed validation, give the submitted values and the errors,
+/// such that the user sees what was wrong without retyping everything.
+/// Each field is a `<div class="field">` with a label and an input, followed by a
+/// `<p class="field-error">` if there are errors.
+#[derive(Debug, Clone)]
+pub struct FormBuilder<T> {
+    form: Form,
+    values: Vec<(String, String)>,
+    errors: Vec<(String, String)>,
+    form_errors: Vec<String>,
+    submit: String,
+    fields: PhantomData<T>,
+}
+
+impl<T: HtmlForm> FormBuilder<T> {
+    pub fn new<U: Into<SafeUrl>>(method: Method, action: U) -> Self {
+        Self {
+            form: Form::new(method, action),
+            values: vec![],
+            errors: vec![],
+            form_errors: vec![],
+            submit: "Submit".to_string(),
+            fields: PhantomData,
+        }
+    }
+
+    /// Fill in the form with what the user submitted, e.g. the raw form body as pairs.
+    #[must_use]
+    pub fn submitted<I, K, V>(mut self, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.values.extend(
+            pairs
+                .into_iter()
+                .map(|(name, value)| (name.as_ref().to_string(), value.as_ref().to_string())),
+        );
+        self
+    }
+
+    /// Show an error for the field with the given name.
+    /// If no visible field has that name, e.g. for a hidden field, it is shown for the form.
+    #[must_use]
+    pub fn error(mut self, name: &str, message: &str) -> Self {
+        self.errors.push((name.to_string(), message.to_string()));
+        self
+    }
+
+    /// Show an error not about any one field, above the fields.
+    #[must_use]
+    pub fn form_error(mut self, message: &str) -> Self {
+        self.form_errors.push(message.to_string());
+        self
+    }
+
+    /// The text of the submit button. Defaults to `Submit`.
+    #[must_use]
+    pub fn submit(mut self, label: &str) -> Self {
+        self.submit = label.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Node {
+        let fields = T::fields();
+        let mut form = self.form.into_node();
+
+        let unshown = self.errors.iter().filter(|(name, _)| {
+            !fields
+                .iter()
+                .any(|field| field.name == name && field.kind != FieldKind::Hidden)
+        });
+        for message in self
+            .form_errors
+            .iter()
+            .chain(unshown.map(|(_, message)| message))
+        {
+            form = form.kid(P.class("form-error").text(escape_attribute_value(message)));
+        }
+
+        for field in fields {
+            let value = self
+                .values
+                .iter()
+                .rev()
+                .find(|(name, _)| name == field.name)
+                .map_or("", |(_, value)| value.as_str());
+            let errors = self
+                .errors
+                .iter()
+                .filter(|(name, _)| name == field.name)
+                .map(|(_, message)| message.as_str())
+                .collect::<Vec<_>>();
+
+            form = form.kid(field.render(value, &errors));
+        }
+
+        form.kid(Input::submit(&self.submit))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: HtmlForm + serde::Serialize> FormBuilder<T> {
+    /// Fill in the form from an existing value, e.g. when editing.
+    /// Each field gets the value serialized under its name.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not serialize into fields, e.g. if it is a number.
+    pub fn values(mut self, values: &T) -> Result<Self, serde_json::Error> {
+        let fields = serde_json::from_value::<Fields>(serde_json::to_value(values)?)?;
+
+        self.values.extend(
+            T::fields()
+                .into_iter()
+                .map(|field| (field.name.to_string(), field_text(fields.get(field.name)))),
+        );
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct Signup {
+        username: String,
+        email: String,
+        age: Option<u8>,
+        born: String,
+        newsletter: bool,
+    }
+
+    impl HtmlForm for Signup {
+        fn fields() -> Vec<Field> {
+            vec![
+                Field::new::<String>("username", "Username")
+                    .minlength(3)
+                    .maxlength(20)
+                    .pattern("[a-z]+")
+                    .autocomplete(Autofill::Username),
+                Field::new::<String>("email", "Email").kind(FieldKind::Email),
+                Field::new::<Option<u8>>("age", "Age").min(13).max(150),
+                Field::new::<String>("born", "Born")
+                    .kind(FieldKind::Date)
+                    .max("2022-12-31"),
+                Field::new::<bool>("newsletter", "Send me news & updates"),
+            ]
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn render_from_values() {
+        let signup = Signup {
+            username: "pg".to_string(),
+            email: "pg@example.com".to_string(),
+            age: None,
+            born: "1964-11-13".to_string(),
+            newsletter: true,
+        };
+
+        let result = FormBuilder::new(Method::Post, "/signup")
+            .values(&signup)
+            .unwrap()
+            .submit("Sign up")
+            .build()
+            .render_string()
+            .unwrap();
+
+        assert_eq!(
+            concat!(
+                r#"<form method="post" action="/signup">"#,
+                r#"<div class="field"><label for="username">Username</label>"#,
+                r#"<input id="username" type="text" name="username" value="pg" minlength="3" maxlength="20" pattern="[a-z]+" required="" autocomplete="username"></div>"#,
+                r#"<div class="field"><label for="email">Email</label>"#,
+                r#"<input id="email" type="email" name="email" value="pg@example.com" required=""></div>"#,
+                r#"<div class="field"><label for="age">Age</label>"#,
+                r#"<input id="age" type="number" name="age" value="" min="13" max="150"></div>"#,
+                r#"<div class="field"><label for="born">Born</label>"#,
+                r#"<input id="born" type="date" name="born" value="1964-11-13" max="2022-12-31" required=""></div>"#,
+                r#"<div class="field"><label for="newsletter">Send me news &amp; updates</label>"#,
+                r#"<input id="newsletter" type="checkbox" name="newsletter" checked="" value="true"></div>"#,
+                r#"<input type="submit" value="Sign up">"#,
+                "</form>"
+            ),
+            result
+        );
+    }
+
+    #[test]
+    fn render_submitted_with_errors() {
+        let result = FormBuilder::<Signup>::new(Method::Post, "/signup")
+            .submitted([("username", "<b>pg</b>"), ("email", "pg")])
+            .error("email", "Not an email address.")
+            .error("email", "Try again.")
+            .form_error("Could not sign up.")
+            .build()
+            .render_string()
+            .unwrap();
+
+        assert!(result.contains(r#"<p class="form-error">Could not sign up.</p>"#));
+        assert!(result.contains(r#"value="&lt;b&gt;pg&lt;/b&gt;""#));
+        assert!(result.contains(concat!(
+            r#"<input id="email" aria-invalid="true" aria-describedby="email-error" type="email" name="email" value="pg" required="">"#,
+            r#"<p class="field-error" id="email-error">Not an email address. Try again.</p>"#
+        )));
+        assert!(result
+            .contains(r#"<input id="newsletter" type="checkbox" name="newsletter" value="true">"#));
+        assert_eq!(1, result.matches("aria-invalid").count());
+    }
+
+    struct Login;
+
+    impl HtmlForm for Login {
+        fn fields() -> Vec<Field> {
+            vec![
+                Field::new::<String>("password", "Password").kind(FieldKind::Password),
+                Field::new::<String>("token", "Token").kind(FieldKind::Hidden),
+            ]
+        }
+    }
+
+    #[test]
+    fn render_unshown_errors_for_the_form() {
+        let result = FormBuilder::<Login>::new(Method::Post, "/login")
+            .submitted([("password", "hunter2"), ("token", "abc")])
+            .error("password", "Wrong password.")
+            .error("token", "Expired, reload the page.")
+            .error("captcha", "Wrong answer.")
+            .form_error("Could not log in.")
+            .build()
+            .render_string()
+            .unwrap();
+
+        assert!(!result.contains("hunter2"));
+        assert!(result.contains(concat!(
+            r#"<p class="form-error">Could not log in.</p>"#,
+            r#"<p class="form-error">Expired, reload the page.</p>"#,
+            r#"<p class="form-error">Wrong answer.</p>"#
+        )));
+        assert!(result.contains(r#"<input id="token" type="hidden" name="token" value="abc">"#));
+        assert!(
+            result.contains(r#"<p class="field-error" id="password-error">Wrong password.</p>"#)
+        );
+    }
+}
@@ -14,11 +14,28 @@ pub mod global_attributes;
 /// Attributes.
 pub mod attributes;
 
+/// Typed CSS, for the `style` attribute and `<style>` elements.
+pub mod css;
+
 /// The tree.
 pub mod document_tree;
 
+/// Content Security Policy for rendered documents.
+pub mod csp;
+
+/// Turn untrusted HTML into safe trees, see [`sanitize::Sanitizer`].
+pub mod sanitize;
+
+/// Forms generated from structs, see [`html_form::HtmlForm`].
+pub mod html_form;
+
+/// Tables of rows, see [`data_table::DataTable`].
+pub mod data_table;
+
 /// HTML document templates.
 pub mod template;
 
+mod hash;
+
 /// Testing stuff
 pub mod science_lab;
@@ -0,0 +1,823 @@
+use crate::{
+    attributes::href::SafeUrl,
+    document_tree::{o, Node},
+    global_attributes::{escape_attribute_value, Dir, Lang},
+    tags::{
+        a::Rel, td::Colspan, Blockquote, Br, Caption, Code, Del, Div, Em, Hr, Img, Ins, Kbd, Li,
+        Mark, Ol, Pre, Small, Span, Strong, Sub, Sup, Table, Tbody, Td, Tfoot, Th, Thead, Tr, Ul,
+        A, B, H1, H2, H3, H4, H5, H6, I, P, S, U,
+    },
+};
+
+/// Elements which are removed together with everything inside them.
+const DROPPED: &[&str] = &[
+    "applet",
+    "base",
+    "embed",
+    "frame",
+    "frameset",
+    "head",
+    "iframe",
+    "link",
+    "math",
+    "meta",
+    "noembed",
+    "noframes",
+    "noscript",
+    "object",
+    "option",
+    "plaintext",
+    "script",
+    "select",
+    "style",
+    "svg",
+    "template",
+    "textarea",
+    "title",
+    "xmp",
+];
+
+/// Elements whose content is not markup, so the tokenizer skips to their end tag.
+const RAW_TEXT: &[&str] = &[
+    "iframe",
+    "noembed",
+    "noframes",
+    "noscript",
+    "plaintext",
+    "script",
+    "style",
+    "textarea",
+    "title",
+    "xmp",
+];
+
+/// Elements which never have content nor an end tag.
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elements which implicitly end an open `<p>`.
+const CLOSES_P: &[&str] = &[
+    "blockquote",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "ol",
+    "p",
+    "pre",
+    "table",
+    "ul",
+];
+
+/// The elements a [`Sanitizer`] can produce, see [`Sanitizer::allow_tags`].
+pub const SUPPORTED_TAGS: &[&str] = &[
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "caption",
+    "code",
+    "del",
+    "div",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "ins",
+    "kbd",
+    "li",
+    "mark",
+    "ol",
+    "p",
+    "pre",
+    "s",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// The attributes a [`Sanitizer`] can keep, see [`Sanitizer::allow_attribute`].
+///
+/// `href` on `a`, `src`, `width` and `height` on `img`, `colspan` on `td` and `th`,
+/// and `title`, `lang`, `dir` and `class` on any element.
+pub const SUPPORTED_ATTRIBUTES: &[&str] = &[
+    "href", "src", "width", "height", "colspan", "title", "lang", "dir", "class",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Text(String),
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+    },
+    End(String),
+}
+
+/// Decode character references such as `&amp;` and `&#x27;`.
+///
+/// Only the named references commonly produced by HTML escaping are known,
+/// others are left as is.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp + 1..];
+
+        match decode_reference(rest) {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => decoded.push('&'),
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Decode the reference at the start of the text, which follows a `&`.
+/// Returns the character and the length of the reference including `;`.
+fn decode_reference(text: &str) -> Option<(char, usize)> {
+    let end = text.find(';').filter(|end| *end <= 32)?;
+    let reference = &text[..end];
+
+    let c = if let Some(number) = reference.strip_prefix('#') {
+        let code = number
+            .strip_prefix(['x', 'X'])
+            .map_or_else(|| number.parse(), |hex| u32::from_str_radix(hex, 16))
+            .ok()?;
+
+        char::from_u32(code)
+            .filter(|c| *c != '\0')
+            .unwrap_or(char::REPLACEMENT_CHARACTER)
+    } else {
+        match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            _ => return None,
+        }
+    };
+
+    Some((c, end + 1))
+}
+
+fn starts_with_letter(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphabetic())
+}
+
+/// Parse a start tag, given the text following its `<`.
+/// Returns the tag and the text after it.
+fn start_tag(text: &str) -> (Token, &str) {
+    let is_delimiter = |c: char| c.is_ascii_whitespace() || c == '/' || c == '>';
+
+    let end = text.find(is_delimiter).unwrap_or(text.len());
+    let name = text[..end].to_ascii_lowercase();
+    let mut rest = &text[end..];
+    let mut attributes: Vec<(String, String)> = vec![];
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+
+        if let Some(after) = rest.strip_prefix('>') {
+            rest = after;
+            break;
+        }
+        if rest.is_empty() {
+            break;
+        }
+
+        // The first character belongs to the name even if it is a `=`.
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| is_delimiter(*c) || *c == '=')
+            .map_or(rest.len(), |(i, _)| i);
+        let attribute = rest[..end].to_ascii_lowercase();
+        rest = rest[end..].trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            rest = after.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+            let (raw, after) = if let Some(quote @ ('"' | '\'')) = rest.chars().next() {
+                let quoted = &rest[1..];
+                let end = quoted.find(quote).unwrap_or(quoted.len());
+                (&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+            } else {
+                let end = rest
+                    .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                    .unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            };
+            value = decode(raw);
+            rest = after;
+        }
+
+        // Browsers use the first of duplicate attributes.
+        if !attributes.iter().any(|(name, _)| *name == attribute) {
+            attributes.push((attribute, value));
+        }
+    }
+
+    (Token::Start { name, attributes }, rest)
+}
+
+/// Split HTML into text and tags. Comments, doctypes and the like are left out,
+/// as is the content of raw text elements such as `<script>`.
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while let Some(lt) = rest.find('<') {
+        if lt > 0 {
+            tokens.push(Token::Text(decode(&rest[..lt])));
+        }
+        let after = &rest[lt + 1..];
+
+        if let Some(comment) = after.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if after.starts_with(['!', '?']) {
+            rest = after.find('>').map_or("", |end| &after[end + 1..]);
+        } else if let Some(end_tag) = after.strip_prefix('/').filter(|t| starts_with_letter(t)) {
+            let end = end_tag.find('>').unwrap_or(end_tag.len());
+            let name = end_tag[..end]
+                .split(|c: char| c.is_ascii_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+
+            tokens.push(Token::End(name));
+            rest = end_tag.get(end + 1..).unwrap_or_default();
+        } else if starts_with_letter(after) {
+            let (token, after) = start_tag(after);
+            rest = after;
+
+            if let Token::Start { name, .. } = &token {
+                if RAW_TEXT.contains(&name.as_str()) {
+                    let close = format!("</{name}");
+                    let end = rest.to_ascii_lowercase().find(&close);
+                    let name = name.clone();
+
+                    rest = end.map_or("", |end| {
+                        let after = &rest[end..];
+                        after.find('>').map_or("", |gt| &after[gt + 1..])
+                    });
+                    tokens.push(token);
+                    tokens.push(Token::End(name));
+                    continue;
+                }
+            }
+            tokens.push(token);
+        } else {
+            tokens.push(Token::Text("<".into()));
+            rest = after;
+        }
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(decode(rest)));
+    }
+
+    tokens
+}
+
+#[derive(Debug)]
+enum Entry {
+    /// An allowed element.
+    Keep(Node),
+    /// A disallowed element, whose content is kept.
+    Unwrap,
+    /// A disallowed element, whose content is removed too.
+    Drop,
+}
+
+#[derive(Debug)]
+struct Open {
+    name: String,
+    entry: Entry,
+}
+
+/// The elements being built, innermost last. The first is the root.
+struct Stack(Vec<Open>);
+
+impl Stack {
+    fn parent(&mut self) -> &mut Node {
+        self.0
+            .iter_mut()
+            .rev()
+            .find_map(|open| {
+                if let Entry::Keep(node) = &mut open.entry {
+                    Some(node)
+                } else {
+                    None
+                }
+            })
+            .expect("The root is always kept")
+    }
+
+    fn dropping(&self) -> bool {
+        self.0.iter().any(|open| matches!(open.entry, Entry::Drop))
+    }
+
+    fn position(&self, name: &str, boundaries: &[&str]) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .skip(1)
+            .rev()
+            .take_while(|(_, open)| !boundaries.contains(&open.name.as_str()))
+            .find(|(_, open)| open.name == name)
+            .map(|(i, _)| i)
+    }
+
+    /// Close elements until the one at the given position is closed.
+    fn close(&mut self, position: usize) {
+        while self.0.len() > position {
+            let open = self.0.pop().expect("The stack is not empty");
+
+            if let Entry::Keep(node) = open.entry {
+                self.parent().push_kid(node);
+            }
+        }
+    }
+
+    /// Close elements which the given element ends without an end tag,
+    /// e.g. a `<p>` ends a previous `<p>`.
+    fn close_implied(&mut self, name: &str) {
+        if CLOSES_P.contains(&name) {
+            if let Some(position) = self.position("p", &["table", "td", "th"]) {
+                self.close(position);
+            }
+        }
+        if name == "li" {
+            if let Some(position) = self.position("li", &["ul", "ol"]) {
+                self.close(position);
+            }
+        }
+    }
+}
+
+/// Turns untrusted HTML into a safe [`Node`] by keeping only allowed elements and attributes.
+///
+/// The input is parsed, and allowed elements are rebuilt from [`crate::tags`] types,
+/// so the output is well formed regardless of the input.
+/// Other elements are removed while keeping their text,
+/// except for e.g. `<script>` and `<style>` which are removed entirely.
+/// Event handlers, `style` and any other attributes not allowed are removed,
+/// and URLs are checked, see [`SafeUrl`].
+/// Links get `rel="nofollow noopener"`, see [`Sanitizer::with_link_rel`].
+///
+/// Start from a preset and adjust it:
+///
+/// ```
+/// use html_strong::sanitize::Sanitizer;
+///
+/// let sanitizer = Sanitizer::comments().allow_tags(&["h3"]).deny_tags(&["pre"]);
+///
+/// let node = sanitizer.sanitize(r#"<h3 onclick="x()">Hi</h3><script>alert(1)</script>"#);
+/// assert_eq!("<h3>Hi</h3>", node.render_string().unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sanitizer {
+    tags: Vec<String>,
+    attributes: Vec<(String, String)>,
+    link_rel: Vec<Rel>,
+}
+
+impl Sanitizer {
+    /// Allow nothing but text.
+    #[must_use]
+    pub fn text_only() -> Self {
+        Self {
+            tags: vec![],
+            attributes: vec![],
+            link_rel: vec![Rel::Nofollow, Rel::Noopener],
+        }
+    }
+
+    /// Paragraphs, line breaks and inline formatting.
+    #[must_use]
+    pub fn basic_text() -> Self {
+        Self::text_only().allow_tags(&["b", "br", "code", "em", "i", "p", "strong", "u"])
+    }
+
+    /// What user comments typically need, e.g. on Hacker News:
+    /// [`Sanitizer::basic_text`] with links, code blocks, quotes and lists.
+    #[must_use]
+    pub fn comments() -> Self {
+        Self::basic_text()
+            .allow_tags(&["a", "blockquote", "li", "ol", "pre", "ul"])
+            .allow_attribute("a", "href")
+    }
+
+    /// [`Sanitizer::comments`] with headings, images, tables, edits and layout elements.
+    #[must_use]
+    pub fn rich_content() -> Self {
+        Self::comments()
+            .allow_tags(&[
+                "caption", "del", "div", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "img", "ins",
+                "kbd", "mark", "s", "small", "span", "sub", "sup", "table", "tbody", "td", "tfoot",
+                "th", "thead", "tr",
+            ])
+            .allow_attribute("img", "src")
+            .allow_attribute("img", "width")
+            .allow_attribute("img", "height")
+            .allow_attribute("td", "colspan")
+            .allow_attribute("th", "colspan")
+            .allow_attribute("*", "title")
+            .allow_attribute("*", "lang")
+            .allow_attribute("*", "dir")
+    }
+
+    /// Allow elements. Only [`SUPPORTED_TAGS`] can be allowed, others are ignored.
+    #[must_use]
+    pub fn allow_tags(mut self, tags: &[&str]) -> Self {
+        for tag in tags {
+            let tag = tag.to_ascii_lowercase();
+
+            if SUPPORTED_TAGS.contains(&tag.as_str()) && !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+        self
+    }
+
+    /// Disallow elements, keeping their content.
+    #[must_use]
+    pub fn deny_tags(mut self, tags: &[&str]) -> Self {
+        self.tags
+            .retain(|tag| !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)));
+        self
+    }
+
+    /// Allow an attribute on an element, or on all elements if the element is `*`.
+    /// Only [`SUPPORTED_ATTRIBUTES`] can be allowed, others are ignored.
+    #[must_use]
+    pub fn allow_attribute(mut self, tag: &str, attribute: &str) -> Self {
+        let allowed = (tag.to_ascii_lowercase(), attribute.to_ascii_lowercase());
+
+        if SUPPORTED_ATTRIBUTES.contains(&allowed.1.as_str()) && !self.attributes.contains(&allowed)
+        {
+            self.attributes.push(allowed);
+        }
+        self
+    }
+
+    /// Disallow an attribute previously allowed with the same arguments.
+    #[must_use]
+    pub fn deny_attribute(mut self, tag: &str, attribute: &str) -> Self {
+        self.attributes
+            .retain(|(t, a)| !(t.eq_ignore_ascii_case(tag) && a.eq_ignore_ascii_case(attribute)));
+        self
+    }
+
+    /// Set the relationships added to every link. Defaults to `nofollow noopener`.
+    #[must_use]
+    pub fn with_link_rel(mut self, rel: &[Rel]) -> Self {
+        self.link_rel = rel.to_vec();
+        self
+    }
+
+    fn allows_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    fn allows_attribute(&self, tag: &str, attribute: &str) -> bool {
+        self.attributes
+            .iter()
+            .any(|(t, a)| (t == tag || t == "*") && a == attribute)
+    }
+
+    /// Build an allowed element, or `None` if it cannot be built from its attributes.
+    fn element(&self, name: &str, attributes: &[(String, String)]) -> Option<Node> {
+        let attribute = |attribute: &str| {
+            attributes
+                .iter()
+                .find(|(a, _)| a == attribute && self.allows_attribute(name, attribute))
+                .map(|(_, value)| value.as_str())
+        };
+        let url = |name: &str| attribute(name).and_then(|url| SafeUrl::new(url.trim()).ok());
+        let number = |name: &str| {
+            attribute(name)
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .filter(|n| (1..=Colspan::MAX).contains(n))
+        };
+
+        let mut node = match name {
+            "a" => {
+                let a = url("href").map_or_else(A::new, |href| A::new().with_href(href));
+                o(self.link_rel.iter().fold(a, |a, rel| a.with_rel(*rel)))
+            }
+            "b" => o(B),
+            "blockquote" => o(Blockquote::new()),
+            "br" => o(Br),
+            "caption" => o(Caption),
+            "code" => o(Code),
+            "del" => o(Del::new()),
+            "div" => o(Div),
+            "em" => o(Em),
+            "h1" => o(H1),
+            "h2" => o(H2),
+            "h3" => o(H3),
+            "h4" => o(H4),
+            "h5" => o(H5),
+            "h6" => o(H6),
+            "hr" => o(Hr),
+            "i" => o(I),
+            "img" => {
+                let src = url("src")?;
+
+                match (number("width"), number("height")) {
+                    (Some(width), Some(height)) => o(Img::new_sized(src, width, height)),
+                    _ => o(Img::new(src)),
+                }
+            }
+            "ins" => o(Ins::new()),
+            "kbd" => o(Kbd),
+            "li" => o(Li),
+            "mark" => o(Mark),
+            "ol" => o(Ol::new()),
+            "p" => o(P),
+            "pre" => o(Pre),
+            "s" => o(S),
+            "small" => o(Small),
+            "span" => o(Span),
+            "strong" => o(Strong),
+            "sub" => o(Sub),
+            "sup" => o(Sup),
+            "table" => o(Table),
+            "tbody" => o(Tbody),
+            "td" => o(number("colspan")
+                .and_then(|span| Td::colspan(span).ok())
+                .unwrap_or_default()),
+            "tfoot" => o(Tfoot),
+            "th" => o(number("colspan")
+                .and_then(|span| Th::colspan(span).ok())
+                .unwrap_or_default()),
+            "thead" => o(Thead),
+            "tr" => o(Tr),
+            "u" => o(U),
+            "ul" => o(Ul),
+            _ => return None,
+        };
+
+        if let Some(title) = attribute("title") {
+            node = node.set_title(title);
+        }
+        if let Some(lang) = attribute("lang").and_then(|lang| Lang::new(lang).ok()) {
+            node = node.set_lang(lang);
+        }
+        if let Some(dir) = attribute("dir") {
+            let dir = match dir.trim().to_ascii_lowercase().as_str() {
+                "ltr" => Some(Dir::Ltr),
+                "rtl" => Some(Dir::Rtl),
+                "auto" => Some(Dir::Auto),
+                _ => None,
+            };
+
+            if let Some(dir) = dir {
+                node = node.add_attr(dir);
+            }
+        }
+        if let Some(class) = attribute("class") {
+            node = node.add_class(class);
+        }
+
+        Some(node)
+    }
+
+    /// Parse the HTML and keep only what is allowed.
+    ///
+    /// The result has no element of its own, so it can be added anywhere as is.
+    #[must_use]
+    pub fn sanitize(&self, html: &str) -> Node {
+        let mut stack = Stack(vec![Open {
+            name: String::new(),
+            entry: Entry::Keep(Node::root()),
+        }]);
+
+        for token in tokenize(html) {
+            match token {
+                Token::Text(text) => {
+                    if !stack.dropping() {
+                        stack.parent().push_text(&escape_attribute_value(&text));
+                    }
+                }
+                Token::Start { name, attributes } => {
+                    stack.close_implied(&name);
+
+                    let entry = if stack.dropping() || DROPPED.contains(&name.as_str()) {
+                        Entry::Drop
+                    } else if self.allows_tag(&name) {
+                        self.element(&name, &attributes)
+                            .map_or(Entry::Drop, Entry::Keep)
+                    } else {
+                        Entry::Unwrap
+                    };
+
+                    if VOID.contains(&name.as_str()) {
+                        if let Entry::Keep(node) = entry {
+                            stack.parent().push_kid(node);
+                        }
+                    } else {
+                        stack.0.push(Open { name, entry });
+                    }
+                }
+                Token::End(name) => {
+                    if let Some(position) = stack.position(&name, &[]) {
+                        stack.close(position);
+                    }
+                }
+            }
+        }
+
+        stack.close(1);
+        match stack.0.pop().map(|open| open.entry) {
+            Some(Entry::Keep(root)) => root,
+            _ => unreachable!("The root is always kept"),
+        }
+    }
+}
+
+impl Default for Sanitizer {
+    /// See [`Sanitizer::comments`].
+    fn default() -> Self {
+        Self::comments()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn sanitize(sanitizer: &Sanitizer, html: &str) -> String {
+        sanitizer.sanitize(html).render_string().unwrap()
+    }
+
+    #[test]
+    fn hacker_news_comment() {
+        let html = r#"I&#x27;d say so.<p>See <a href="https:&#x2F;&#x2F;example.com&#x2F;?a=1&amp;b=2" rel="nofollow">https:&#x2F;&#x2F;example.com</a><p><pre><code>  let x = 1 &lt; 2;
+</code></pre><p><i>fin</i>"#;
+
+        assert_eq!(
+            concat!(
+                "I&#39;d say so.",
+                r#"<p>See <a href="https://example.com/?a=1&amp;b=2" rel="nofollow noopener">https://example.com</a></p>"#,
+                "<p></p><pre><code>  let x = 1 &lt; 2;\n</code></pre>",
+                "<p><i>fin</i></p>"
+            ),
+            sanitize(&Sanitizer::comments(), html)
+        );
+    }
+
+    #[test]
+    fn strips_scripts_styles_and_handlers() {
+        let html = r#"<p onclick="steal()" style="color:red">Hi<script>alert("</p>")</script><style>p{}</style><img src=x onerror=alert(1)></p><svg><a href="/x">svg</a></svg>"#;
+
+        assert_eq!("<p>Hi</p>", sanitize(&Sanitizer::comments(), html));
+    }
+
+    #[test]
+    fn blocks_script_urls() {
+        let html =
+            r#"<a href=" java&#x09;script:alert(1)">x</a><a href='JAVASCRIPT:alert(1)'>y</a>"#;
+
+        assert_eq!(
+            r#"<a rel="nofollow noopener">x</a><a rel="nofollow noopener">y</a>"#,
+            sanitize(&Sanitizer::comments(), html)
+        );
+    }
+
+    #[test]
+    fn unwraps_disallowed_tags() {
+        assert_eq!(
+            "Hello <b>bold</b> world",
+            sanitize(
+                &Sanitizer::basic_text(),
+                "<div>Hello <font color=red><b>bold</b></font> world</div>"
+            )
+        );
+        assert_eq!(
+            "a &lt;b&gt; c",
+            sanitize(&Sanitizer::text_only(), "a <b>&lt;b&gt;</b> c")
+        );
+    }
+
+    #[test]
+    fn escapes_attribute_values() {
+        assert_eq!(
+            r#"<span title="&quot; onmouseover=&quot;x()">t</span>"#,
+            sanitize(
+                &Sanitizer::rich_content(),
+                r#"<span title='" onmouseover="x()'>t</span>"#
+            )
+        );
+    }
+
+    #[test]
+    fn well_formed_output() {
+        assert_eq!(
+            "<ul><li>one</li><li>two <b>three</b></li></ul>after",
+            sanitize(
+                &Sanitizer::comments(),
+                "<ul><li>one<li>two <b>three</ul>after</b></li>"
+            )
+        );
+        assert_eq!(
+            "<ol><li>one</li><li>two</li></ol>",
+            sanitize(
+                &Sanitizer::comments(),
+                r#"<ol start="0"><li>one<li>two</ol>"#
+            )
+        );
+        assert_eq!("1 &lt; 2 &lt;", sanitize(&Sanitizer::comments(), "1 < 2 <"));
+        assert_eq!("x", sanitize(&Sanitizer::comments(), "x<!-- <p>"));
+    }
+
+    #[test]
+    fn rich_content_images_and_classes() {
+        let sanitizer = Sanitizer::rich_content().allow_attribute("*", "class");
+
+        assert_eq!(
+            r#"<img class="wide" src="/cat.png" height="20" width="10"></img><img src="data:image/png;base64,AA=="></img>"#,
+            sanitize(
+                &sanitizer,
+                r#"<img class="wide" src="/cat.png" width=10 height="20"><img src="data:image/png;base64,AA=="><img src="data:text/html,x">"#
+            )
+        );
+    }
+
+    #[test]
+    fn text_level_elements() {
+        assert_eq!(
+            "<strong>bold</strong> H2O",
+            sanitize(
+                &Sanitizer::basic_text(),
+                "<strong>bold</strong> H<sub>2</sub>O"
+            )
+        );
+        assert_eq!(
+            "<del>old</del><ins>new</ins> <kbd>Ctrl</kbd> x<sup>2</sup>",
+            sanitize(
+                &Sanitizer::rich_content(),
+                r#"<del datetime="2024">old</del><ins>new</ins> <kbd>Ctrl</kbd> x<sup>2</sup>"#
+            )
+        );
+    }
+
+    #[test]
+    fn customised() {
+        let sanitizer = Sanitizer::comments()
+            .deny_tags(&["A"])
+            .allow_tags(&["h1", "marquee"])
+            .with_link_rel(&[Rel::Ugc]);
+
+        assert_eq!(
+            "<h1>link</h1>scroll",
+            sanitize(
+                &sanitizer,
+                r#"<h1><a href="/">link</a></h1><marquee>scroll</marquee>"#
+            )
+        );
+        assert_eq!(
+            r#"<a href="/" rel="ugc">x</a>"#,
+            sanitize(
+                &Sanitizer::comments().with_link_rel(&[Rel::Ugc]),
+                r#"<a href="/">x</a>"#
+            )
+        );
+    }
+}
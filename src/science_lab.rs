@@ -1,4 +1,10 @@
+use crate::css::{Css, ScopedStyle};
 use crate::document_tree::Node;
+use crate::global_attributes::{
+    AccessKey, Aria, Autocapitalize, Autofocus, ContentEditable, Data, Dir, Draggable,
+    EnterKeyHint, Event, Hidden, Inert, InputMode, Lang, Nonce, On, Part, Popover, Slot,
+    Spellcheck, Tabindex, Translate,
+};
 use crate::tags::Tag;
 
 // struct Foo;
@@ -84,6 +90,34 @@ pub trait NodeExt {
         self.clone().into_node().add_class(class.as_ref())
     }
 
+    /// Add classes only if the condition holds, see [`Node::add_class_if`].
+    fn class_if<S>(&self, condition: bool, class: S) -> Node
+    where
+        S: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone()
+            .into_node()
+            .add_class_if(condition, class.as_ref())
+    }
+
+    /// Add each class whose condition holds, e.g. `[("selected", is_selected)]`.
+    fn classes_if<'a, I>(&self, classes: I) -> Node
+    where
+        I: IntoIterator<Item = (&'a str, bool)>,
+        Self: Clone,
+    {
+        self.clone().into_node().add_classes_if(classes)
+    }
+
+    /// Add a class local to a component, see [`Node::add_scoped_class`].
+    fn scoped_class(&self, style: &ScopedStyle, class: &str) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_scoped_class(style, class)
+    }
+
     fn kid<K>(&self, kid: K) -> Node
     where
         K: NodeExt,
@@ -107,6 +141,214 @@ pub trait NodeExt {
     {
         self.clone().into_node().add_style(style.as_ref())
     }
+
+    /// Add typed inline style declarations, see [`Node::add_css`].
+    fn css(&self, css: Css) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_css(css)
+    }
+
+    /// Add a custom `data-*` attribute.
+    /// The name is given without the `data-` prefix.
+    ///
+    /// # Panics
+    ///
+    /// If the name is not a valid data attribute name, see [`Data::try_new`].
+    fn data<N, V>(&self, name: N, value: V) -> Node
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone()
+            .into_node()
+            .add_attr(Data::new(name.as_ref(), value.as_ref()))
+    }
+
+    /// Add an `aria-*` state or property.
+    fn aria(&self, aria: Aria) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(aria)
+    }
+
+    /// Add an inline event handler attribute, e.g. `onclick`, see [`On`].
+    fn on<S>(&self, event: Event, script: S) -> Node
+    where
+        S: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone()
+            .into_node()
+            .add_attr(On::new(event, script.as_ref()))
+    }
+
+    /// Handle an event in a way compatible with a strict Content Security Policy,
+    /// see [`crate::global_attributes::event::Listener`].
+    fn listen<S>(&self, event: Event, script: S) -> Node
+    where
+        S: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone()
+            .into_node()
+            .add_listener(event, script.as_ref())
+    }
+
+    /// Set the language, see [`Node::set_lang`].
+    fn lang(&self, lang: Lang) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().set_lang(lang)
+    }
+
+    /// Hide the element, see [`Hidden`].
+    fn hidden(&self, hidden: Hidden) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(hidden)
+    }
+
+    /// Set the element's position in sequential keyboard navigation.
+    fn tabindex(&self, index: i32) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(Tabindex(index))
+    }
+
+    /// Set the text direction, see [`Dir`].
+    fn dir(&self, dir: Dir) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(dir)
+    }
+
+    /// Let the user edit the element's content, see [`ContentEditable`].
+    fn contenteditable(&self, editable: ContentEditable) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(editable)
+    }
+
+    /// Whether the element can be dragged, from a `bool` or [`Draggable`].
+    fn draggable<D>(&self, draggable: D) -> Node
+    where
+        D: Into<Draggable>,
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(draggable.into())
+    }
+
+    /// Whether the browser checks the spelling, from a `bool` or [`Spellcheck`].
+    fn spellcheck<S>(&self, spellcheck: S) -> Node
+    where
+        S: Into<Spellcheck>,
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(spellcheck.into())
+    }
+
+    /// Whether the content is translated along with the page, from a `bool` or [`Translate`].
+    fn translate<T>(&self, translate: T) -> Node
+    where
+        T: Into<Translate>,
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(translate.into())
+    }
+
+    /// Add a keyboard shortcut hint, see [`AccessKey`] for several alternatives.
+    fn accesskey(&self, key: char) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(AccessKey::new(key))
+    }
+
+    /// How virtual keyboards capitalize text typed into the element, see [`Autocapitalize`].
+    fn autocapitalize(&self, autocapitalize: Autocapitalize) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(autocapitalize)
+    }
+
+    /// Focus the element when the page loads or its dialog/popover is shown.
+    fn autofocus(&self) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(Autofocus)
+    }
+
+    /// Make the element and its content non-interactive, see [`Inert`].
+    fn inert(&self) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(Inert)
+    }
+
+    /// Label the enter key of virtual keyboards, see [`EnterKeyHint`].
+    fn enterkeyhint(&self, hint: EnterKeyHint) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(hint)
+    }
+
+    /// Which virtual keyboard suits the element, see [`InputMode`].
+    fn inputmode(&self, mode: InputMode) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(mode)
+    }
+
+    /// Allow an inline script or style under a Content Security Policy, see [`Nonce`].
+    fn nonce<S>(&self, nonce: S) -> Node
+    where
+        S: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone()
+            .into_node()
+            .add_attr(Nonce::new(nonce.as_ref()))
+    }
+
+    /// Turn the element into a popover, see [`Popover`].
+    fn popover(&self, popover: Popover) -> Node
+    where
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(popover)
+    }
+
+    /// Put the element into the named slot of a shadow root.
+    fn slot<S>(&self, slot: S) -> Node
+    where
+        S: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(Slot::new(slot.as_ref()))
+    }
+
+    /// Whitespace separates several part names.
+    fn part<S>(&self, part: S) -> Node
+    where
+        S: AsRef<str>,
+        Self: Clone,
+    {
+        self.clone().into_node().add_attr(Part::new(part.as_ref()))
+    }
 }
 
 impl<T> NodeExt for T
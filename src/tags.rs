@@ -30,6 +30,10 @@ pub_mod_and_export![
     h1::H1,
     h2::H2,
     h3::H3,
+    h4::H4,
+    h5::H5,
+    h6::H6,
+    heading::Heading,
     hr::Hr,
     li::Li,
     p::P,
@@ -63,7 +67,62 @@ pub_mod_and_export![
     iframe::Iframe,
     main::Main,
     video::Video,
-    source::Source
+    source::Source,
+    select::Select,
+    option::SelectOption,
+    optgroup::Optgroup,
+    datalist::Datalist,
+    fieldset::Fieldset,
+    legend::Legend,
+    output::Output,
+    progress::Progress,
+    meter::Meter,
+    thead::Thead,
+    tbody::Tbody,
+    tfoot::Tfoot,
+    caption::Caption,
+    colgroup::Colgroup,
+    col::Col,
+    article::Article,
+    section::Section,
+    aside::Aside,
+    header::Header,
+    hgroup::Hgroup,
+    address::Address,
+    search::Search,
+    figure::Figure,
+    figcaption::Figcaption,
+    menu::Menu,
+    strong::Strong,
+    small::Small,
+    s::S,
+    mark::Mark,
+    abbr::Abbr,
+    cite::Cite,
+    q::Q,
+    dfn::Dfn,
+    kbd::Kbd,
+    samp::Samp,
+    var::Var,
+    sub::Sub,
+    sup::Sup,
+    bdi::Bdi,
+    bdo::Bdo,
+    wbr::Wbr,
+    data::Data,
+    ins::Ins,
+    del::Del,
+    ruby::Ruby,
+    rt::Rt,
+    rp::Rp,
+    ol::Ol,
+    dl::Dl,
+    dt::Dt,
+    dd::Dd,
+    time::Time,
+    details::Details,
+    summary::Summary,
+    dialog::Dialog
 ];
 
 crate_mod![invisible::Invisible, root::Root];
@@ -1,3 +1,4 @@
+use crate::attributes::href::SafeUrl;
 use crate::global_attributes::Attribute;
 
 use super::{link::Href, Tag};
@@ -10,7 +11,6 @@ pub enum Target {
     Top,
 }
 
-// TODO: Verify these
 impl Attribute for Target {
     fn name(&self) -> &'static str {
         "target"
@@ -18,19 +18,84 @@ impl Attribute for Target {
 
     fn value(&self) -> String {
         match self {
-            Target::Blank => "blank",
-            Target::Parent => "parent",
-            Target::Self_ => "self",
-            Target::Top => "top",
+            Target::Blank => "_blank",
+            Target::Parent => "_parent",
+            Target::Self_ => "_self",
+            Target::Top => "_top",
         }
         .into()
     }
 }
 
+/// How the linked resource relates to the current document.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/rel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rel {
+    Alternate,
+    Author,
+    Bookmark,
+    External,
+    Help,
+    License,
+    Next,
+    /// Do not endorse the link, e.g. for user content.
+    Nofollow,
+    /// Do not give the opened page access to this one via `window.opener`.
+    Noopener,
+    Noreferrer,
+    Prev,
+    Search,
+    Sponsored,
+    Tag,
+    /// User generated content.
+    Ugc,
+}
+
+impl Rel {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Alternate => "alternate",
+            Self::Author => "author",
+            Self::Bookmark => "bookmark",
+            Self::External => "external",
+            Self::Help => "help",
+            Self::License => "license",
+            Self::Next => "next",
+            Self::Nofollow => "nofollow",
+            Self::Noopener => "noopener",
+            Self::Noreferrer => "noreferrer",
+            Self::Prev => "prev",
+            Self::Search => "search",
+            Self::Sponsored => "sponsored",
+            Self::Tag => "tag",
+            Self::Ugc => "ugc",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Rels(pub(crate) Vec<Rel>);
+
+impl Attribute for Rels {
+    fn name(&self) -> &'static str {
+        "rel"
+    }
+
+    fn value(&self) -> String {
+        self.0
+            .iter()
+            .map(|rel| rel.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct A {
     href: Option<Href>,
     target: Option<Target>,
+    rel: Rels,
 }
 
 impl A {
@@ -39,16 +104,17 @@ impl A {
         Self {
             href: None,
             target: None,
+            rel: Rels(Vec::new()),
         }
     }
 
     #[must_use]
-    pub fn href(href: &str) -> Self {
+    pub fn href<U: Into<SafeUrl>>(href: U) -> Self {
         Self::new().with_href(href)
     }
 
     #[must_use]
-    pub fn with_href(mut self, href: &str) -> Self {
+    pub fn with_href<U: Into<SafeUrl>>(mut self, href: U) -> Self {
         self.href = Some(Href::new(href));
         self
     }
@@ -58,6 +124,15 @@ impl A {
         self.target = Some(target);
         self
     }
+
+    /// Add a link relationship. Adding one already present does nothing.
+    #[must_use]
+    pub fn with_rel(mut self, rel: Rel) -> Self {
+        if !self.rel.0.contains(&rel) {
+            self.rel.0.push(rel);
+        }
+        self
+    }
 }
 
 impl Default for A {
@@ -72,11 +147,22 @@ impl Tag for A {
     }
 
     fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
-        match (&self.href, &self.target) {
-            (None, None) => None,
-            (None, Some(t)) => Some(vec![t]),
-            (Some(h), None) => Some(vec![h]),
-            (Some(h), Some(t)) => Some(vec![h, t]),
+        let mut attrs: Vec<&dyn Attribute> = vec![];
+
+        if let Some(href) = &self.href {
+            attrs.push(href);
+        }
+        if let Some(target) = &self.target {
+            attrs.push(target);
+        }
+        if !self.rel.0.is_empty() {
+            attrs.push(&self.rel);
+        }
+
+        if attrs.is_empty() {
+            None
+        } else {
+            Some(attrs)
         }
     }
 }
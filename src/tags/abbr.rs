@@ -0,0 +1,64 @@
+use crate::global_attributes::{escape_attribute_value, Attribute};
+
+use super::Tag;
+
+#[derive(Debug, Clone)]
+struct Title(String);
+
+impl Attribute for Title {
+    fn name(&self) -> &'static str {
+        "title"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// An abbreviation or acronym.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/abbr).
+#[derive(Debug, Clone, Default)]
+pub struct Abbr {
+    title: Option<Title>,
+}
+
+impl Abbr {
+    /// An abbreviation with its expansion, e.g. `HyperText Markup Language` for `HTML`.
+    #[must_use]
+    pub fn new(expansion: &str) -> Self {
+        Self {
+            title: Some(Title(expansion.to_string())),
+        }
+    }
+}
+
+impl Tag for Abbr {
+    fn name(&self) -> &'static str {
+        "abbr"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.title
+            .as_ref()
+            .map(|title| vec![title as &dyn Attribute])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_abbr() {
+        assert_eq!(
+            r#"<abbr title="Hypertext &quot;Markup&quot; Language">HTML</abbr>"#,
+            Abbr::new(r#"Hypertext "Markup" Language"#)
+                .text("HTML")
+                .render_string()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Contact information for the nearest article, or the document.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/address).
+#[derive(Debug, Clone)]
+pub struct Address;
+
+impl Tag for Address {
+    fn name(&self) -> &'static str {
+        "address"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Self-contained content, e.g. a blog post or a comment.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/article).
+#[derive(Debug, Clone)]
+pub struct Article;
+
+impl Tag for Article {
+    fn name(&self) -> &'static str {
+        "article"
+    }
+}
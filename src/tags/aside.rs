@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Content only indirectly related to the main content, e.g. a sidebar.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/aside).
+#[derive(Debug, Clone)]
+pub struct Aside;
+
+impl Tag for Aside {
+    fn name(&self) -> &'static str {
+        "aside"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Text isolated from the surrounding text's direction, e.g. user names of unknown script.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/bdi).
+#[derive(Debug, Clone)]
+pub struct Bdi;
+
+impl Tag for Bdi {
+    fn name(&self) -> &'static str {
+        "bdi"
+    }
+}
@@ -0,0 +1,52 @@
+use crate::global_attributes::{Attribute, Dir};
+
+use super::Tag;
+
+/// Text in an explicit direction, overriding the bidirectional algorithm.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/bdo).
+#[derive(Debug, Clone)]
+pub struct Bdo {
+    dir: Dir,
+}
+
+impl Bdo {
+    #[must_use]
+    pub const fn ltr() -> Self {
+        Self { dir: Dir::Ltr }
+    }
+
+    #[must_use]
+    pub const fn rtl() -> Self {
+        Self { dir: Dir::Rtl }
+    }
+}
+
+impl Tag for Bdo {
+    fn name(&self) -> &'static str {
+        "bdo"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        Some(vec![&self.dir])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_bdo() {
+        assert_eq!(
+            r#"<bdo dir="rtl">abc</bdo>"#,
+            Bdo::rtl().text("abc").render_string().unwrap()
+        );
+        assert_eq!(
+            r#"<bdo dir="ltr">abc</bdo>"#,
+            Bdo::ltr().text("abc").render_string().unwrap()
+        );
+    }
+}
@@ -1,34 +1,284 @@
-use crate::global_attributes::Attribute;
+use std::marker::PhantomData;
 
-use super::Tag;
+use crate::attributes::href::SafeUrl;
+use crate::global_attributes::{escape_attribute_value, Attribute, Flag, Other};
 
-#[derive(Debug, Clone)]
-pub enum Type {
-    Submit,
+use super::{
+    form::{Enctype, Method},
+    Tag,
+};
+
+/// The kinds of [`Button`], i.e. the values of its `type` attribute.
+///
+/// Only submit buttons may override how the form is submitted, e.g. with [`Button::formaction`]:
+///
+/// ```compile_fail
+/// use html_strong::tags::Button;
+///
+/// let _ = Button::reset().formaction("/elsewhere");
+/// ```
+pub mod kind {
+    use std::fmt;
+
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// A button type.
+    pub trait Kind: sealed::Sealed + fmt::Debug + Clone + Copy + Default + Send + 'static {
+        /// The value of the `type` attribute.
+        const TYPE: &'static str;
+    }
+
+    macro_rules! kinds {
+        ( $( $(#[$meta:meta])* $kind:ident => $type:literal ),* $(,)? ) => {
+            $(
+                $(#[$meta])*
+                #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+                pub struct $kind;
+
+                impl sealed::Sealed for $kind {}
+
+                impl Kind for $kind {
+                    const TYPE: &'static str = $type;
+                }
+            )*
+        };
+    }
+
+    kinds![
+        /// Submits the form it belongs to.
+        Submit => "submit",
+        /// Resets the inputs of the form it belongs to.
+        Reset => "reset",
+        /// Does nothing, e.g. for scripts or [`super::Button::popovertarget`] to handle.
+        Button => "button",
+    ];
 }
 
-impl Attribute for Type {
+use kind::Kind;
+
+#[derive(Debug, Clone)]
+struct ButtonType(&'static str);
+
+impl Attribute for ButtonType {
     fn name(&self) -> &'static str {
         "type"
     }
 
     fn value(&self) -> String {
-        "submit".into()
+        self.0.to_string()
+    }
+}
+
+/// What a button does to the popover it controls, see [`Button::popovertarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopoverTargetAction {
+    #[default]
+    Toggle,
+    Show,
+    Hide,
+}
+
+impl PopoverTargetAction {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Toggle => "toggle",
+            Self::Show => "show",
+            Self::Hide => "hide",
+        }
+    }
+}
+
+impl Attribute for PopoverTargetAction {
+    fn name(&self) -> &'static str {
+        "popovertargetaction"
+    }
+
+    fn value(&self) -> String {
+        self.as_str().into()
+    }
+}
+
+/// What a button does to the element it controls, see [`Button::commandfor`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#command).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    ShowModal,
+    Close,
+    RequestClose,
+    ShowPopover,
+    HidePopover,
+    TogglePopover,
+    /// A command handled by scripts listening for the `command` event.
+    /// Must start with `--`, which is added if missing.
+    Custom(String),
+}
+
+impl Attribute for Command {
+    fn name(&self) -> &'static str {
+        "command"
+    }
+
+    fn value(&self) -> String {
+        let command = match self {
+            Self::ShowModal => "show-modal".into(),
+            Self::Close => "close".into(),
+            Self::RequestClose => "request-close".into(),
+            Self::ShowPopover => "show-popover".into(),
+            Self::HidePopover => "hide-popover".into(),
+            Self::TogglePopover => "toggle-popover".into(),
+            Self::Custom(command) if command.starts_with("--") => command.clone(),
+            Self::Custom(command) => format!("--{command}"),
+        };
+
+        escape_attribute_value(&command)
     }
 }
 
 /// A button.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button).
+///
+/// The kind of button is part of the type, see [`kind`].
 #[derive(Debug, Clone)]
-pub struct Button {
-    type_: Type,
+pub struct Button<K: Kind = kind::Submit> {
+    type_: ButtonType,
+    disabled: Option<Flag>,
+    name: Option<Other>,
+    value: Option<Other>,
+    form: Option<Other>,
+    popovertarget: Option<Other>,
+    popovertargetaction: Option<PopoverTargetAction>,
+    commandfor: Option<Other>,
+    command: Option<Command>,
+    formaction: Option<Other>,
+    formmethod: Option<Other>,
+    formenctype: Option<Other>,
+    formnovalidate: Option<Flag>,
+    kind: PhantomData<K>,
+}
+
+impl<K: Kind> Button<K> {
+    const fn of_kind() -> Self {
+        Self {
+            type_: ButtonType(K::TYPE),
+            disabled: None,
+            name: None,
+            value: None,
+            form: None,
+            popovertarget: None,
+            popovertargetaction: None,
+            commandfor: None,
+            command: None,
+            formaction: None,
+            formmethod: None,
+            formenctype: None,
+            formnovalidate: None,
+            kind: PhantomData,
+        }
+    }
+
+    fn other(name: &'static str, value: &str) -> Other {
+        Other {
+            name,
+            value: value.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.disabled = Some(Flag("disabled"));
+        self
+    }
+
+    /// Submit `value` under `name` when this button submits the form,
+    /// such that the server knows which of several buttons was pressed.
+    #[must_use]
+    pub fn name_value(mut self, name: &str, value: &str) -> Self {
+        self.name = Some(Self::other("name", name));
+        self.value = Some(Self::other("value", value));
+        self
+    }
+
+    /// Associate the button with a form elsewhere in the document, by the form's id.
+    #[must_use]
+    pub fn form(mut self, form_id: &str) -> Self {
+        self.form = Some(Self::other("form", form_id));
+        self
+    }
+
+    /// Toggle the popover with the given id, see [`crate::global_attributes::Popover`].
+    #[must_use]
+    pub fn popovertarget(mut self, popover_id: &str) -> Self {
+        self.popovertarget = Some(Self::other("popovertarget", popover_id));
+        self
+    }
+
+    /// Show or hide instead of toggling the popover, see [`Button::popovertarget`].
+    #[must_use]
+    pub const fn popovertargetaction(mut self, action: PopoverTargetAction) -> Self {
+        self.popovertargetaction = Some(action);
+        self
+    }
+
+    /// Run the command on the element with the given id, e.g. show a modal `<dialog>`.
+    #[must_use]
+    pub fn commandfor(mut self, element_id: &str, command: Command) -> Self {
+        self.commandfor = Some(Self::other("commandfor", element_id));
+        self.command = Some(command);
+        self
+    }
+}
+
+impl Button<kind::Submit> {
+    /// Submit to this URL instead of the form's action.
+    #[must_use]
+    pub fn formaction<U: Into<SafeUrl>>(mut self, url: U) -> Self {
+        self.formaction = Some(Self::other("formaction", url.into().as_str()));
+        self
+    }
+
+    /// Submit with this method instead of the form's.
+    #[must_use]
+    pub fn formmethod(mut self, method: Method) -> Self {
+        self.formmethod = Some(Self::other("formmethod", method.as_str()));
+        self
+    }
+
+    /// Encode the data like this instead of the form's `enctype`.
+    #[must_use]
+    pub fn formenctype(mut self, enctype: Enctype) -> Self {
+        self.formenctype = Some(Self::other("formenctype", enctype.as_str()));
+        self
+    }
+
+    /// Skip the browser's validation of the inputs, e.g. for saving a draft.
+    #[must_use]
+    pub const fn formnovalidate(mut self) -> Self {
+        self.formnovalidate = Some(Flag("formnovalidate"));
+        self
+    }
 }
 
 impl Button {
+    /// A submit button.
     #[must_use]
     pub const fn new() -> Self {
-        Self {
-            type_: Type::Submit,
-        }
+        Self::of_kind()
+    }
+
+    /// Reset the inputs of the form.
+    #[must_use]
+    pub const fn reset() -> Button<kind::Reset> {
+        Button::of_kind()
+    }
+
+    /// A button without default behaviour.
+    #[allow(clippy::self_named_constructors)]
+    #[must_use]
+    pub const fn button() -> Button<kind::Button> {
+        Button::of_kind()
     }
 }
 
@@ -38,12 +288,105 @@ impl Default for Button {
     }
 }
 
-impl Tag for Button {
+impl<K: Kind> Tag for Button<K> {
     fn name(&self) -> &'static str {
         "button"
     }
 
     fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
-        Some(vec![&self.type_])
+        let mut attrs: Vec<&dyn Attribute> = vec![&self.type_];
+
+        if let Some(disabled) = &self.disabled {
+            attrs.push(disabled);
+        }
+
+        for other in [&self.name, &self.value, &self.form, &self.popovertarget]
+            .into_iter()
+            .flatten()
+        {
+            attrs.push(other);
+        }
+
+        if let Some(action) = &self.popovertargetaction {
+            attrs.push(action);
+        }
+
+        if let Some(commandfor) = &self.commandfor {
+            attrs.push(commandfor);
+        }
+
+        if let Some(command) = &self.command {
+            attrs.push(command);
+        }
+
+        for other in [&self.formaction, &self.formmethod, &self.formenctype]
+            .into_iter()
+            .flatten()
+        {
+            attrs.push(other);
+        }
+
+        if let Some(formnovalidate) = &self.formnovalidate {
+            attrs.push(formnovalidate);
+        }
+
+        Some(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn render<K: Kind>(button: Button<K>) -> String {
+        button.text("x").render_string().unwrap()
+    }
+
+    #[test]
+    fn render_types() {
+        assert_eq!(r#"<button type="submit">x</button>"#, render(Button::new()));
+        assert_eq!(
+            r#"<button type="reset">x</button>"#,
+            render(Button::reset())
+        );
+        assert_eq!(
+            r#"<button type="button">x</button>"#,
+            render(Button::button())
+        );
+        assert_eq!(
+            r#"<button type="button" disabled="">x</button>"#,
+            render(Button::button().disabled().disabled())
+        );
+    }
+
+    #[test]
+    fn render_multi_button_form() {
+        assert_eq!(
+            r#"<button type="submit" name="vote" value="up&amp;away" form="item-1">x</button>"#,
+            render(Button::new().name_value("vote", "up&away").form("item-1"))
+        );
+    }
+
+    #[test]
+    fn render_invokers() {
+        assert_eq!(
+            r#"<button type="button" popovertarget="menu" popovertargetaction="show">x</button>"#,
+            render(
+                Button::button()
+                    .popovertarget("menu")
+                    .popovertargetaction(PopoverTargetAction::Show)
+            )
+        );
+        assert_eq!(
+            r#"<button type="button" commandfor="confirm" command="show-modal">x</button>"#,
+            render(Button::button().commandfor("confirm", Command::ShowModal))
+        );
+        assert_eq!(
+            r#"<button type="button" commandfor="video" command="--rewind">x</button>"#,
+            render(Button::button().commandfor("video", Command::Custom("rewind".into())))
+        );
     }
 }
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The title of a table, which must be its first child.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/caption).
+#[derive(Debug, Clone)]
+pub struct Caption;
+
+impl Tag for Caption {
+    fn name(&self) -> &'static str {
+        "caption"
+    }
+}
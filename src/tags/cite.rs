@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The title of a work, e.g. a book or a film.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/cite).
+#[derive(Debug, Clone)]
+pub struct Cite;
+
+impl Tag for Cite {
+    fn name(&self) -> &'static str {
+        "cite"
+    }
+}
@@ -0,0 +1,64 @@
+use crate::global_attributes::Attribute;
+
+use super::{
+    td::{Colspan, InvalidSpan},
+    Tag,
+};
+
+/// The number of columns a [`Col`] or [`super::Colgroup`] spans.
+#[derive(Debug, Clone)]
+pub(crate) struct Span(usize);
+
+impl Span {
+    pub(crate) const fn new(span: usize) -> Result<Self, InvalidSpan> {
+        match InvalidSpan::check("span", span, 1, Colspan::MAX) {
+            Ok(span) => Ok(Self(span)),
+            Err(invalid) => Err(invalid),
+        }
+    }
+}
+
+impl Attribute for Span {
+    fn name(&self) -> &'static str {
+        "span"
+    }
+
+    fn value(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// One or more columns within a [`super::Colgroup`], e.g. to style them.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/col).
+#[derive(Debug, Clone, Default)]
+pub struct Col {
+    span: Option<Span>,
+}
+
+impl Col {
+    /// Several columns.
+    ///
+    /// # Errors
+    ///
+    /// If `span` is not from 1 to [`Colspan::MAX`].
+    pub const fn span(span: usize) -> Result<Self, InvalidSpan> {
+        match Span::new(span) {
+            Ok(span) => Ok(Self { span: Some(span) }),
+            Err(invalid) => Err(invalid),
+        }
+    }
+}
+
+impl Tag for Col {
+    fn name(&self) -> &'static str {
+        "col"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.span.as_ref().map(|span| vec![span as &dyn Attribute])
+    }
+
+    fn close_tag(&self) -> bool {
+        false
+    }
+}
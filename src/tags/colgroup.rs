@@ -0,0 +1,34 @@
+use crate::global_attributes::Attribute;
+
+use super::{col::Span, td::InvalidSpan, Tag};
+
+/// A group of columns, either with [`super::Col`] children or a span.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/colgroup).
+#[derive(Debug, Clone, Default)]
+pub struct Colgroup {
+    span: Option<Span>,
+}
+
+impl Colgroup {
+    /// A group of columns without [`super::Col`] children.
+    ///
+    /// # Errors
+    ///
+    /// If `span` is not from 1 to [`super::td::Colspan::MAX`].
+    pub const fn span(span: usize) -> Result<Self, InvalidSpan> {
+        match Span::new(span) {
+            Ok(span) => Ok(Self { span: Some(span) }),
+            Err(invalid) => Err(invalid),
+        }
+    }
+}
+
+impl Tag for Colgroup {
+    fn name(&self) -> &'static str {
+        "colgroup"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.span.as_ref().map(|span| vec![span as &dyn Attribute])
+    }
+}
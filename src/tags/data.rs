@@ -0,0 +1,61 @@
+use crate::global_attributes::{escape_attribute_value, Attribute};
+
+use super::Tag;
+
+#[derive(Debug, Clone)]
+struct Value(String);
+
+impl Attribute for Value {
+    fn name(&self) -> &'static str {
+        "value"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// Content with a machine-readable value, e.g. a product name with its id.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/data).
+#[derive(Debug, Clone)]
+pub struct Data {
+    value: Value,
+}
+
+impl Data {
+    #[must_use]
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: Value(value.to_string()),
+        }
+    }
+}
+
+impl Tag for Data {
+    fn name(&self) -> &'static str {
+        "data"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        Some(vec![&self.value])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_data() {
+        assert_eq!(
+            r#"<data value="398">Mini ketchup</data>"#,
+            Data::new("398")
+                .text("Mini ketchup")
+                .render_string()
+                .unwrap()
+        );
+    }
+}
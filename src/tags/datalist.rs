@@ -0,0 +1,29 @@
+use crate::{document_tree::Node, science_lab::NodeExt};
+
+use super::{SelectOption, Tag};
+
+/// Values suggested for an input, see [`super::input::Input::list`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/datalist).
+///
+/// The input refers to the datalist by its id.
+#[derive(Debug, Clone)]
+pub struct Datalist;
+
+impl Datalist {
+    /// A datalist with the given id, suggesting the given values.
+    pub fn with_values<I, V>(id: &str, values: I) -> Node
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        values.into_iter().fold(Self.id(id), |datalist, value| {
+            datalist.kid(SelectOption::new(value.as_ref()))
+        })
+    }
+}
+
+impl Tag for Datalist {
+    fn name(&self) -> &'static str {
+        "datalist"
+    }
+}
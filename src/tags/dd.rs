@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The description of the [`super::Dt`] before it.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dd).
+#[derive(Debug, Clone)]
+pub struct Dd;
+
+impl Tag for Dd {
+    fn name(&self) -> &'static str {
+        "dd"
+    }
+}
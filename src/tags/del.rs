@@ -0,0 +1,40 @@
+use crate::attributes::href::SafeUrl;
+use crate::global_attributes::Attribute;
+
+use super::{ins::Edit, Tag};
+
+/// Content removed from the document, usually struck through.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/del).
+#[derive(Debug, Clone, Default)]
+pub struct Del(Edit);
+
+impl Del {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A URL explaining the change, e.g. a changelog entry.
+    #[must_use]
+    pub fn cite<U: Into<SafeUrl>>(mut self, url: U) -> Self {
+        self.0.cite(url);
+        self
+    }
+
+    /// When the change was made, e.g. `2024-05-01` or `2024-05-01T12:00Z`.
+    #[must_use]
+    pub fn datetime(mut self, datetime: &str) -> Self {
+        self.0.datetime(datetime);
+        self
+    }
+}
+
+impl Tag for Del {
+    fn name(&self) -> &'static str {
+        "del"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.0.attributes()
+    }
+}
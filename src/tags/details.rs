@@ -0,0 +1,131 @@
+use crate::global_attributes::{escape_attribute_value, Attribute, Flag};
+
+use super::Tag;
+
+#[derive(Debug, Clone)]
+struct Name(String);
+
+impl Attribute for Name {
+    fn name(&self) -> &'static str {
+        "name"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// A disclosure widget, showing its content when its [`super::Summary`] is clicked.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/details).
+///
+/// Needs no scripts, e.g. for collapsing comment threads.
+#[derive(Debug, Clone, Default)]
+pub struct Details {
+    open: Option<Flag>,
+    name: Option<Name>,
+}
+
+impl Details {
+    /// Closed details, see [`Details::open`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the content initially.
+    #[must_use]
+    pub const fn open(mut self) -> Self {
+        self.open = Some(Flag("open"));
+        self
+    }
+
+    /// Details sharing a name form an exclusive accordion,
+    /// where opening one closes the others.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(Name(name.to_string()));
+        self
+    }
+}
+
+impl Tag for Details {
+    fn name(&self) -> &'static str {
+        "details"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![];
+
+        if let Some(open) = &self.open {
+            attrs.push(open);
+        }
+        if let Some(name) = &self.name {
+            attrs.push(name);
+        }
+
+        if attrs.is_empty() {
+            None
+        } else {
+            Some(attrs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::Node,
+        science_lab::NodeExt,
+        tags::{Div, Summary, A},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn comment(author: &str, text: &str, replies: Vec<Node>) -> Node {
+        replies.into_iter().fold(
+            Details::new()
+                .open()
+                .class("comment")
+                .kid(Summary.kid(A::href(format!("/user?id={author}")).text(author)))
+                .kid(Div.class("commtext").text(text)),
+            |comment, reply| comment.kid(reply),
+        )
+    }
+
+    #[test]
+    fn render_collapsible_comment_thread() {
+        let thread = comment(
+            "pg",
+            "First!",
+            vec![comment("dang", "Please don't.", vec![])],
+        );
+
+        assert_eq!(
+            concat!(
+                r#"<details class="comment" open="">"#,
+                r#"<summary><a href="/user?id=pg">pg</a></summary>"#,
+                r#"<div class="commtext">First!</div>"#,
+                r#"<details class="comment" open="">"#,
+                r#"<summary><a href="/user?id=dang">dang</a></summary>"#,
+                r#"<div class="commtext">Please don't.</div>"#,
+                "</details>",
+                "</details>"
+            ),
+            thread.render_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn render_exclusive_accordion() {
+        assert_eq!(
+            r#"<details name="faq"><summary>Why?</summary>Because.</details>"#,
+            Details::new()
+                .name("faq")
+                .kid(Summary.text("Why?"))
+                .text("Because.")
+                .render_string()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The term being defined by the surrounding paragraph.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dfn).
+#[derive(Debug, Clone)]
+pub struct Dfn;
+
+impl Tag for Dfn {
+    fn name(&self) -> &'static str {
+        "dfn"
+    }
+}
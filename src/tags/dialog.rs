@@ -0,0 +1,143 @@
+use crate::global_attributes::{Attribute, Flag};
+
+use super::Tag;
+
+/// How a modal [`Dialog`] may be closed by the user, besides by scripts or forms.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dialog#closedby).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosedBy {
+    /// Clicking outside the dialog, or a close request such as pressing `Esc`.
+    Any,
+    /// A close request such as pressing `Esc`.
+    CloseRequest,
+    /// Only by scripts or forms, e.g. a [`super::Form::dialog`].
+    None,
+}
+
+impl Attribute for ClosedBy {
+    fn name(&self) -> &'static str {
+        "closedby"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Any => "any",
+            Self::CloseRequest => "closerequest",
+            Self::None => "none",
+        }
+        .into()
+    }
+}
+
+/// A dialog box, e.g. a confirmation.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dialog).
+///
+/// Show it as a modal with [`super::Button::commandfor`] and
+/// [`super::button::Command::ShowModal`], and close it with a [`super::Form::dialog`].
+#[derive(Debug, Clone, Default)]
+pub struct Dialog {
+    open: Option<Flag>,
+    closedby: Option<ClosedBy>,
+}
+
+impl Dialog {
+    /// A closed dialog.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the dialog initially, but not as a modal.
+    #[must_use]
+    pub const fn open(mut self) -> Self {
+        self.open = Some(Flag("open"));
+        self
+    }
+
+    #[must_use]
+    pub const fn closedby(mut self, closedby: ClosedBy) -> Self {
+        self.closedby = Some(closedby);
+        self
+    }
+}
+
+impl Tag for Dialog {
+    fn name(&self) -> &'static str {
+        "dialog"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![];
+
+        if let Some(open) = &self.open {
+            attrs.push(open);
+        }
+        if let Some(closedby) = &self.closedby {
+            attrs.push(closedby);
+        }
+
+        if attrs.is_empty() {
+            None
+        } else {
+            Some(attrs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        global_attributes::Popover,
+        science_lab::NodeExt,
+        tags::{button::Command, Button, Div, Form},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_modal_dialog() {
+        let page = Div
+            .kid(
+                Button::button()
+                    .commandfor("hide", Command::ShowModal)
+                    .text("hide"),
+            )
+            .kid(
+                Dialog::new()
+                    .closedby(ClosedBy::Any)
+                    .id("hide")
+                    .text("Hide this story?")
+                    .kid(Form::dialog().kid(Button::new().text("OK"))),
+            );
+
+        assert_eq!(
+            concat!(
+                "<div>",
+                r#"<button type="button" commandfor="hide" command="show-modal">hide</button>"#,
+                r#"<dialog id="hide" closedby="any">Hide this story?"#,
+                r#"<form method="dialog"><button type="submit">OK</button></form>"#,
+                "</dialog>",
+                "</div>"
+            ),
+            page.render_string().unwrap()
+        );
+        assert_eq!(
+            r#"<dialog open=""></dialog>"#,
+            o(Dialog::new().open()).render_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn render_popover_wiring() {
+        let menu = Div
+            .kid(Button::button().popovertarget("menu").text("more"))
+            .kid(Div.id("menu").popover(Popover::Auto).text("flag"));
+
+        assert_eq!(
+            r#"<div><button type="button" popovertarget="menu">more</button><div id="menu" popover="auto">flag</div></div>"#,
+            menu.render_string().unwrap()
+        );
+    }
+}
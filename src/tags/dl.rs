@@ -0,0 +1,49 @@
+use crate::{document_tree::Node, science_lab::NodeExt};
+
+use super::{Dd, Dt, Tag};
+
+/// A description list of terms and their descriptions, e.g. a glossary or metadata.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dl).
+#[derive(Debug, Clone)]
+pub struct Dl;
+
+impl Dl {
+    /// The list with each term in a `<dt>`, followed by its description in a `<dd>`.
+    pub fn with_terms<I, T, D>(self, terms: I) -> Node
+    where
+        I: IntoIterator<Item = (T, D)>,
+        T: NodeExt,
+        D: NodeExt,
+    {
+        terms
+            .into_iter()
+            .fold(self.into_node(), |dl, (term, description)| {
+                dl.kid(Dt.kid(term)).kid(Dd.kid(description))
+            })
+    }
+}
+
+impl Tag for Dl {
+    fn name(&self) -> &'static str {
+        "dl"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tags::{Span, B};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_dl_from_terms() {
+        assert_eq!(
+            "<dl><dt><b>HTML</b></dt><dd><span>Markup</span></dd><dt><b>CSS</b></dt><dd><span>Style</span></dd></dl>",
+            Dl.with_terms([("HTML", "Markup"), ("CSS", "Style")]
+                .map(|(term, description)| (B.text(term), Span.text(description))))
+            .render_string()
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// A term in a [`super::Dl`], described by the [`super::Dd`] after it.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dt).
+#[derive(Debug, Clone)]
+pub struct Dt;
+
+impl Tag for Dt {
+    fn name(&self) -> &'static str {
+        "dt"
+    }
+}
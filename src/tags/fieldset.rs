@@ -0,0 +1,98 @@
+use crate::global_attributes::{Attribute, Flag, Other};
+
+use super::Tag;
+
+/// A group of controls within a form, usually captioned by a [`super::Legend`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/fieldset).
+#[derive(Debug, Clone, Default)]
+pub struct Fieldset {
+    disabled: Option<Flag>,
+    name: Option<Other>,
+    form: Option<Other>,
+}
+
+impl Fieldset {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            disabled: None,
+            name: None,
+            form: None,
+        }
+    }
+
+    /// Disable all controls in the group, except those in its legend.
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.disabled = Some(Flag("disabled"));
+        self
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(Other {
+            name: "name",
+            value: name.to_string(),
+        });
+        self
+    }
+
+    /// Associate the group with a form elsewhere in the document, by the form's id.
+    #[must_use]
+    pub fn form(mut self, form_id: &str) -> Self {
+        self.form = Some(Other {
+            name: "form",
+            value: form_id.to_string(),
+        });
+        self
+    }
+}
+
+impl Tag for Fieldset {
+    fn name(&self) -> &'static str {
+        "fieldset"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![];
+
+        if let Some(disabled) = &self.disabled {
+            attrs.push(disabled);
+        }
+
+        for other in [&self.name, &self.form].into_iter().flatten() {
+            attrs.push(other);
+        }
+
+        Some(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        science_lab::NodeExt,
+        tags::{Input, Legend},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_fieldset() {
+        let fieldset = Fieldset::new()
+            .disabled()
+            .name("shipping")
+            .form("checkout")
+            .kid(Legend.text("Shipping"))
+            .kid(Input::number("a"));
+
+        assert_eq!(
+            concat!(
+                r#"<fieldset disabled="" name="shipping" form="checkout"><legend>Shipping</legend>"#,
+                r#"<input type="number" name="a"></fieldset>"#
+            ),
+            fieldset.render_string().unwrap()
+        );
+    }
+}
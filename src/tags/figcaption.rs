@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The caption of a [`super::Figure`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/figcaption).
+#[derive(Debug, Clone)]
+pub struct Figcaption;
+
+impl Tag for Figcaption {
+    fn name(&self) -> &'static str {
+        "figcaption"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Self-contained content, e.g. an image, optionally with a caption in a [`super::Figcaption`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/figure).
+#[derive(Debug, Clone)]
+pub struct Figure;
+
+impl Tag for Figure {
+    fn name(&self) -> &'static str {
+        "figure"
+    }
+}
@@ -1,13 +1,29 @@
-use crate::global_attributes::Attribute;
+use crate::attributes::href::SafeUrl;
+use crate::global_attributes::{Attribute, Flag, Other};
 
-use super::Tag;
+use super::{
+    a::{Rel, Rels, Target},
+    Tag,
+};
 
 /// Method used when submitting form.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Method {
     Get,
     Post,
-    // Dialog, // Todo
+    /// Close the `<dialog>` the form is in, without submitting it anywhere.
+    Dialog,
+}
+
+impl Method {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Dialog => "dialog",
+        }
+    }
 }
 
 impl Attribute for Method {
@@ -16,21 +32,52 @@ impl Attribute for Method {
     }
 
     fn value(&self) -> String {
+        self.as_str().into()
+    }
+}
+
+/// How the form data is encoded when submitted with [`Method::Post`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#enctype).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Enctype {
+    /// `application/x-www-form-urlencoded`, what browsers use by default.
+    #[default]
+    UrlEncoded,
+    /// `multipart/form-data`, needed for file inputs.
+    Multipart,
+    /// `text/plain`, meant for debugging.
+    TextPlain,
+}
+
+impl Enctype {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
         match self {
-            Method::Get => "get",
-            Method::Post => "post",
+            Self::UrlEncoded => "application/x-www-form-urlencoded",
+            Self::Multipart => "multipart/form-data",
+            Self::TextPlain => "text/plain",
         }
-        .into()
     }
 }
 
-/// Url which processes the form.
+impl Attribute for Enctype {
+    fn name(&self) -> &'static str {
+        "enctype"
+    }
+
+    fn value(&self) -> String {
+        self.as_str().into()
+    }
+}
+
+/// Url which processes the form, see [`SafeUrl`].
 #[derive(Debug, Clone)]
-pub struct Action(String);
+pub struct Action(SafeUrl);
 
 impl Action {
-    pub fn new<S: AsRef<str>>(action: S) -> Self {
-        Self(action.as_ref().to_owned())
+    #[must_use]
+    pub fn new<U: Into<SafeUrl>>(action: U) -> Self {
+        Self(action.into())
     }
 }
 
@@ -40,25 +87,110 @@ impl Attribute for Action {
     }
 
     fn value(&self) -> String {
-        self.0.to_string()
+        self.0.value()
     }
 }
 
 /// Data form.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form).
 #[derive(Debug, Clone)]
 pub struct Form {
     method: Method,
-    action: Action,
+    action: Option<Action>,
+    enctype: Option<Enctype>,
+    target: Option<Target>,
+    rel: Rels,
+    attributes: Vec<Box<dyn Attribute>>,
 }
 
 impl Form {
     #[must_use]
-    pub fn new(method: Method, action_url: &str) -> Self {
+    pub fn new<U: Into<SafeUrl>>(method: Method, action_url: U) -> Self {
+        Self::method(method).action(action_url)
+    }
+
+    /// A form submitted to the URL of the current document.
+    #[must_use]
+    pub const fn method(method: Method) -> Self {
         Self {
             method,
-            action: Action(action_url.to_string()),
+            action: None,
+            enctype: None,
+            target: None,
+            rel: Rels(Vec::new()),
+            attributes: Vec::new(),
         }
     }
+
+    /// A form closing the `<dialog>` it is in, see [`Method::Dialog`].
+    #[must_use]
+    pub const fn dialog() -> Self {
+        Self::method(Method::Dialog)
+    }
+
+    #[must_use]
+    pub fn action<U: Into<SafeUrl>>(mut self, action_url: U) -> Self {
+        self.action = Some(Action::new(action_url));
+        self
+    }
+
+    /// Use [`Enctype::Multipart`] for forms with file inputs.
+    #[must_use]
+    pub const fn enctype(mut self, enctype: Enctype) -> Self {
+        self.enctype = Some(enctype);
+        self
+    }
+
+    /// Where to show the response.
+    #[must_use]
+    pub const fn target(mut self, target: Target) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Add a relationship to the resource the form submits to.
+    /// Adding one already present does nothing.
+    #[must_use]
+    pub fn rel(mut self, rel: Rel) -> Self {
+        if !self.rel.0.contains(&rel) {
+            self.rel.0.push(rel);
+        }
+        self
+    }
+
+    /// Whether browsers may autofill the inputs by default.
+    #[must_use]
+    pub fn autocomplete(self, autocomplete: bool) -> Self {
+        self.other("autocomplete", if autocomplete { "on" } else { "off" })
+    }
+
+    /// Skip the browser's validation of the inputs, e.g. when the server validates.
+    #[must_use]
+    pub fn novalidate(mut self) -> Self {
+        self.attributes.retain(|attr| attr.name() != "novalidate");
+        self.attributes.push(Box::new(Flag("novalidate")));
+        self
+    }
+
+    #[must_use]
+    pub fn name(self, name: &str) -> Self {
+        self.other("name", name)
+    }
+
+    /// The character encodings accepted, where only `UTF-8` is valid nowadays.
+    #[must_use]
+    pub fn accept_charset(self, charset: &str) -> Self {
+        self.other("accept-charset", charset)
+    }
+
+    fn other(mut self, name: &'static str, value: &str) -> Self {
+        self.attributes.retain(|attr| attr.name() != name);
+        self.attributes.push(Box::new(Other {
+            name,
+            value: value.to_string(),
+        }));
+        self
+    }
 }
 
 impl Tag for Form {
@@ -67,6 +199,91 @@ impl Tag for Form {
     }
 
     fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
-        Some(vec![&self.method, &self.action])
+        let mut attrs: Vec<&dyn Attribute> = vec![&self.method];
+
+        if let Some(action) = &self.action {
+            attrs.push(action);
+        }
+        if let Some(enctype) = &self.enctype {
+            attrs.push(enctype);
+        }
+        if let Some(target) = &self.target {
+            attrs.push(target);
+        }
+        if !self.rel.0.is_empty() {
+            attrs.push(&self.rel);
+        }
+        attrs.extend(self.attributes.iter().map(AsRef::as_ref));
+
+        Some(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        science_lab::NodeExt,
+        tags::{Button, Input},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_form_attributes() {
+        let form = Form::new(Method::Post, "/upload")
+            .enctype(Enctype::Multipart)
+            .target(Target::Blank)
+            .rel(Rel::Noopener)
+            .autocomplete(false)
+            .novalidate()
+            .name("upload")
+            .accept_charset("UTF-8");
+
+        assert_eq!(
+            r#"<form method="post" action="/upload" enctype="multipart/form-data" target="_blank" rel="noopener" autocomplete="off" novalidate="" name="upload" accept-charset="UTF-8"></form>"#,
+            o(form).render_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn render_dialog_form() {
+        assert_eq!(
+            r#"<form method="dialog"><button type="submit">Close</button></form>"#,
+            Form::dialog()
+                .kid(Button::new().text("Close"))
+                .render_string()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn render_submit_overrides() {
+        let form = Form::new(Method::Post, "/comment")
+            .kid(
+                Input::submit("Preview")
+                    .formaction("/preview")
+                    .formnovalidate(),
+            )
+            .kid(
+                Button::new()
+                    .formaction("/draft")
+                    .formmethod(Method::Get)
+                    .formenctype(Enctype::TextPlain)
+                    .text("Save draft"),
+            )
+            .kid(Input::image("/go.png", "Go").formmethod(Method::Dialog));
+
+        assert_eq!(
+            concat!(
+                r#"<form method="post" action="/comment">"#,
+                r#"<input type="submit" value="Preview" formaction="/preview" formnovalidate="">"#,
+                r#"<button type="submit" formaction="/draft" formmethod="get" formenctype="text/plain">Save draft</button>"#,
+                r#"<input type="image" src="/go.png" alt="Go" formmethod="dialog">"#,
+                "</form>"
+            ),
+            form.render_string().unwrap()
+        );
     }
 }
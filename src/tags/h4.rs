@@ -0,0 +1,11 @@
+use super::Tag;
+
+/// H4.
+#[derive(Debug, Clone)]
+pub struct H4;
+
+impl Tag for H4 {
+    fn name(&self) -> &'static str {
+        "h4"
+    }
+}
@@ -0,0 +1,11 @@
+use super::Tag;
+
+/// H5.
+#[derive(Debug, Clone)]
+pub struct H5;
+
+impl Tag for H5 {
+    fn name(&self) -> &'static str {
+        "h5"
+    }
+}
@@ -0,0 +1,11 @@
+use super::Tag;
+
+/// H6.
+#[derive(Debug, Clone)]
+pub struct H6;
+
+impl Tag for H6 {
+    fn name(&self) -> &'static str {
+        "h6"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Introductory content, e.g. a logo and navigation, or the heading of an article.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/header).
+#[derive(Debug, Clone)]
+pub struct Header;
+
+impl Tag for Header {
+    fn name(&self) -> &'static str {
+        "header"
+    }
+}
@@ -0,0 +1,108 @@
+use super::Tag;
+
+/// A heading of any level, i.e. one of [`super::H1`] to [`super::H6`].
+/// Useful when the level is only known at runtime, e.g. for nested sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heading {
+    level: u8,
+}
+
+impl Heading {
+    /// A heading of the given level, or `None` if it is not from 1 to 6.
+    #[must_use]
+    pub fn level(level: usize) -> Option<Self> {
+        let level = u8::try_from(level)
+            .ok()
+            .filter(|level| (1..=6).contains(level))?;
+
+        Some(Self { level })
+    }
+
+    /// The level, from 1 to 6.
+    #[must_use]
+    pub const fn get(self) -> u8 {
+        self.level
+    }
+
+    /// The heading one level below this one, e.g. for a subsection.
+    /// Below `h6` there are no more levels, so deeper sections also get an `h6`.
+    #[must_use]
+    pub const fn nested(self) -> Self {
+        if self.level < 6 {
+            Self {
+                level: self.level + 1,
+            }
+        } else {
+            self
+        }
+    }
+}
+
+impl Tag for Heading {
+    fn name(&self) -> &'static str {
+        match self.level {
+            1 => "h1",
+            2 => "h2",
+            3 => "h3",
+            4 => "h4",
+            5 => "h5",
+            _ => "h6",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        science_lab::NodeExt,
+        tags::{Article, Figcaption, Figure, Header, Hgroup, Img, Section, H5, P},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn levels() {
+        assert_eq!(None, Heading::level(0));
+        assert_eq!(4, Heading::level(4).unwrap().get());
+        assert_eq!(None, Heading::level(7));
+        assert_eq!(6, Heading::level(6).unwrap().nested().get());
+        assert_eq!(
+            "<h3>x</h3>",
+            Heading::level(2)
+                .unwrap()
+                .nested()
+                .text("x")
+                .render_string()
+                .unwrap()
+        );
+        assert_eq!("<h5></h5>", o(H5).render_string().unwrap());
+    }
+
+    #[test]
+    fn render_semantic_structure() {
+        let article = Article
+            .kid(
+                Header.kid(
+                    Hgroup
+                        .kid(Heading::level(1).unwrap().text("Title"))
+                        .kid(P.text("Tagline")),
+                ),
+            )
+            .kid(
+                Section
+                    .kid(Heading::level(2).unwrap().text("Part"))
+                    .kid(Figure.kid(Img::new("/a.png")).kid(Figcaption.text("A"))),
+            );
+
+        assert_eq!(
+            concat!(
+                "<article><header><hgroup><h1>Title</h1><p>Tagline</p></hgroup></header>",
+                r#"<section><h2>Part</h2><figure><img src="/a.png"></img><figcaption>A</figcaption></figure></section>"#,
+                "</article>"
+            ),
+            article.render_string().unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// A heading together with subtitles or taglines, given as paragraphs.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/hgroup).
+#[derive(Debug, Clone)]
+pub struct Hgroup;
+
+impl Tag for Hgroup {
+    fn name(&self) -> &'static str {
+        "hgroup"
+    }
+}
@@ -1,3 +1,4 @@
+use crate::attributes::href::SafeUrl;
 use crate::global_attributes::Attribute;
 
 use super::{img, Tag};
@@ -12,18 +13,18 @@ pub struct Iframe {
 
 impl Iframe {
     #[must_use]
-    pub fn new(url: &str) -> Self {
+    pub fn new<U: Into<SafeUrl>>(url: U) -> Self {
         Self {
-            src: img::Src(url.to_string()),
+            src: img::Src::new(url),
             width: None,
             height: None,
         }
     }
 
     #[must_use]
-    pub fn new_sized(url: &str, width: usize, height: usize) -> Self {
+    pub fn new_sized<U: Into<SafeUrl>>(url: U, width: usize, height: usize) -> Self {
         Self {
-            src: img::Src(url.to_string()),
+            src: img::Src::new(url),
             width: Some(img::Width(width)),
             height: Some(img::Height(height)),
         }
@@ -1,10 +1,18 @@
+use crate::attributes::href::SafeUrl;
 use crate::global_attributes::Attribute;
 
 use super::Tag;
 
-/// Source image.
+/// Source of e.g. an image or script, see [`SafeUrl`].
 #[derive(Debug, Clone)]
-pub struct Src(pub String);
+pub struct Src(pub SafeUrl);
+
+impl Src {
+    #[must_use]
+    pub fn new<U: Into<SafeUrl>>(url: U) -> Self {
+        Self(url.into())
+    }
+}
 
 impl Attribute for Src {
     fn name(&self) -> &'static str {
@@ -12,7 +20,7 @@ impl Attribute for Src {
     }
 
     fn value(&self) -> String {
-        self.0.to_string()
+        self.0.value()
     }
 }
 
@@ -54,18 +62,18 @@ pub struct Img {
 
 impl Img {
     #[must_use]
-    pub fn new(url: &str) -> Self {
+    pub fn new<U: Into<SafeUrl>>(url: U) -> Self {
         Self {
-            src: Src(url.to_string()),
+            src: Src::new(url),
             width: None,
             height: None,
         }
     }
 
     #[must_use]
-    pub fn new_sized(url: &str, width: usize, height: usize) -> Self {
+    pub fn new_sized<U: Into<SafeUrl>>(url: U, width: usize, height: usize) -> Self {
         Self {
-            src: Src(url.to_string()),
+            src: Src::new(url),
             width: Some(Width(width)),
             height: Some(Height(height)),
         }
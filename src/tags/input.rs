@@ -1,28 +1,238 @@
-use crate::global_attributes::Attribute;
+use std::{fmt, marker::PhantomData};
 
-use super::Tag;
+use crate::attributes::href::SafeUrl;
+use crate::global_attributes::{escape_attribute_value, Attribute, Flag, Other};
 
-#[derive(Debug, Clone)]
-enum InputType {
-    Hidden,
-    Submit,
-    Text,
-    Radio,
+use super::{
+    form::{Enctype, Method},
+    img::Src,
+    Tag,
+};
+
+/// The kinds of [`Input`], i.e. the values of its `type` attribute.
+///
+/// Each kind implements the traits of the attributes valid for it,
+/// such that e.g. [`Input::placeholder`] is only available on text-like inputs.
+///
+/// ```compile_fail
+/// use html_strong::tags::Input;
+///
+/// // Browsers never prefill files.
+/// let _ = Input::file("avatar").value("cat.png");
+/// ```
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attributes)
+/// for which attribute applies to which type.
+pub mod kind {
+    use std::fmt;
+
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// An input type.
+    pub trait Kind: sealed::Sealed + fmt::Debug + Clone + Copy + Default + Send + 'static {
+        /// The value of the `type` attribute.
+        const TYPE: &'static str;
+    }
+
+    /// Types which may have a `value`, i.e. all but files and images.
+    pub trait Valued: Kind {}
+    /// Types which may be autofilled, see [`super::Input::autocomplete`].
+    pub trait Autocomplete: Kind {}
+    /// Types which may have `checked`.
+    pub trait Checkable: Kind {}
+    /// Types whose value is text of limited length, with `minlength`, `maxlength`, `pattern` and `size`.
+    pub trait TextLike: Kind {}
+    /// Types which may have a `placeholder`.
+    pub trait Placeholder: Kind {}
+    /// Types which may be `readonly`.
+    pub trait Readonly: Kind {}
+    /// Types which may be `required`.
+    pub trait Required: Kind {}
+    /// Types which may suggest values from a `<datalist>`, see [`super::Input::list`].
+    pub trait List: Kind {}
+    /// Types which may be `multiple`.
+    pub trait Multiple: Kind {}
+    /// Types submitting the form, which may override how with e.g. `formaction`.
+    pub trait Submits: Kind {}
+    /// Types with `min`, `max` and `step`.
+    pub trait Ranged: Kind {
+        /// What bounds are given as, e.g. a number or a date.
+        type Bound: fmt::Display;
+    }
+
+    macro_rules! kinds {
+        ( $( $(#[$meta:meta])* $kind:ident => $type:literal: [$($cap:ident),*] ),* $(,)? ) => {
+            $(
+                $(#[$meta])*
+                #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+                pub struct $kind;
+
+                impl sealed::Sealed for $kind {}
+
+                impl Kind for $kind {
+                    const TYPE: &'static str = $type;
+                }
+
+                $( impl $cap for $kind {} )*
+            )*
+        };
+    }
+
+    kinds![
+        /// Single line text.
+        Text => "text": [Valued, Autocomplete, TextLike, Placeholder, Readonly, Required, List],
+        /// Text used for searching.
+        Search => "search": [Valued, Autocomplete, TextLike, Placeholder, Readonly, Required, List],
+        Url => "url": [Valued, Autocomplete, TextLike, Placeholder, Readonly, Required, List],
+        Tel => "tel": [Valued, Autocomplete, TextLike, Placeholder, Readonly, Required, List],
+        Email => "email": [Valued, Autocomplete, TextLike, Placeholder, Readonly, Required, List, Multiple],
+        Password => "password": [Valued, Autocomplete, TextLike, Placeholder, Readonly, Required],
+        Number => "number": [Valued, Autocomplete, Placeholder, Readonly, Required, List],
+        /// A number where the exact value is not important, e.g. a slider.
+        Range => "range": [Valued, Autocomplete, List],
+        Date => "date": [Valued, Autocomplete, Readonly, Required, List],
+        Time => "time": [Valued, Autocomplete, Readonly, Required, List],
+        DatetimeLocal => "datetime-local": [Valued, Autocomplete, Readonly, Required, List],
+        Month => "month": [Valued, Autocomplete, Readonly, Required, List],
+        Week => "week": [Valued, Autocomplete, Readonly, Required, List],
+        Color => "color": [Valued, Autocomplete, List],
+        Checkbox => "checkbox": [Valued, Checkable, Required],
+        Radio => "radio": [Valued, Checkable, Required],
+        File => "file": [Required, Multiple],
+        Hidden => "hidden": [Valued, Autocomplete],
+        Submit => "submit": [Valued, Submits],
+        Reset => "reset": [Valued],
+        /// A button without default behaviour.
+        Button => "button": [Valued],
+        /// A graphical submit button.
+        Image => "image": [Submits],
+    ];
+
+    macro_rules! ranged {
+        ( $( $kind:ident => $bound:ty ),* ) => {
+            $( impl Ranged for $kind { type Bound = $bound; } )*
+        };
+    }
+
+    ranged![
+        Number => f64,
+        Range => f64,
+        Date => String,
+        Time => String,
+        DatetimeLocal => String,
+        Month => String,
+        Week => String
+    ];
+}
+
+use kind::Kind;
+
+/// Autofill detail tokens, i.e. values of the `autocomplete` attribute.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Autofill {
+    Off,
+    On,
+    Name,
+    HonorificPrefix,
+    GivenName,
+    AdditionalName,
+    FamilyName,
+    HonorificSuffix,
+    Nickname,
+    Email,
+    Username,
+    NewPassword,
+    CurrentPassword,
+    OneTimeCode,
+    OrganizationTitle,
+    Organization,
+    StreetAddress,
+    AddressLine1,
+    AddressLine2,
+    AddressLine3,
+    AddressLevel1,
+    AddressLevel2,
+    Country,
+    CountryName,
+    PostalCode,
+    CcName,
+    CcNumber,
+    CcExp,
+    CcExpMonth,
+    CcExpYear,
+    CcCsc,
+    CcType,
+    TransactionCurrency,
+    TransactionAmount,
+    Language,
+    Bday,
+    Sex,
+    Tel,
+    Url,
+    Photo,
+}
+
+impl Autofill {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::Name => "name",
+            Self::HonorificPrefix => "honorific-prefix",
+            Self::GivenName => "given-name",
+            Self::AdditionalName => "additional-name",
+            Self::FamilyName => "family-name",
+            Self::HonorificSuffix => "honorific-suffix",
+            Self::Nickname => "nickname",
+            Self::Email => "email",
+            Self::Username => "username",
+            Self::NewPassword => "new-password",
+            Self::CurrentPassword => "current-password",
+            Self::OneTimeCode => "one-time-code",
+            Self::OrganizationTitle => "organization-title",
+            Self::Organization => "organization",
+            Self::StreetAddress => "street-address",
+            Self::AddressLine1 => "address-line1",
+            Self::AddressLine2 => "address-line2",
+            Self::AddressLine3 => "address-line3",
+            Self::AddressLevel1 => "address-level1",
+            Self::AddressLevel2 => "address-level2",
+            Self::Country => "country",
+            Self::CountryName => "country-name",
+            Self::PostalCode => "postal-code",
+            Self::CcName => "cc-name",
+            Self::CcNumber => "cc-number",
+            Self::CcExp => "cc-exp",
+            Self::CcExpMonth => "cc-exp-month",
+            Self::CcExpYear => "cc-exp-year",
+            Self::CcCsc => "cc-csc",
+            Self::CcType => "cc-type",
+            Self::TransactionCurrency => "transaction-currency",
+            Self::TransactionAmount => "transaction-amount",
+            Self::Language => "language",
+            Self::Bday => "bday",
+            Self::Sex => "sex",
+            Self::Tel => "tel",
+            Self::Url => "url",
+            Self::Photo => "photo",
+        }
+    }
 }
 
+#[derive(Debug, Clone)]
+struct InputType(&'static str);
+
 impl Attribute for InputType {
     fn name(&self) -> &'static str {
         "type"
     }
 
     fn value(&self) -> String {
-        match self {
-            InputType::Hidden => "hidden",
-            InputType::Submit => "submit",
-            InputType::Text => "text",
-            InputType::Radio => "radio",
-        }
-        .to_string()
+        self.0.to_string()
     }
 }
 
@@ -35,7 +245,7 @@ impl Attribute for Name {
     }
 
     fn value(&self) -> String {
-        self.0.to_string()
+        escape_attribute_value(&self.0)
     }
 }
 
@@ -48,88 +258,353 @@ impl Attribute for Value {
     }
 
     fn value(&self) -> String {
-        self.0.to_string()
+        escape_attribute_value(&self.0)
     }
 }
 
+/// Input.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input).
+///
+/// The kind of input is part of the type, see [`kind`],
+/// so only attributes valid for that kind can be set.
 #[derive(Debug, Clone)]
-struct Checked;
+pub struct Input<K: Kind = kind::Text> {
+    type_: InputType,
+    name: Option<Name>,
+    checked: Option<Flag>,
+    value: Option<Value>,
+    attributes: Vec<Box<dyn Attribute>>,
+    kind: PhantomData<K>,
+}
 
-impl Attribute for Checked {
-    fn name(&self) -> &'static str {
-        "checked"
+impl<K: Kind> Input<K> {
+    fn new(name: Option<&str>, value: Option<&str>) -> Self {
+        Self {
+            type_: InputType(K::TYPE),
+            name: name.map(|name| Name(name.to_string())),
+            checked: None,
+            value: value.map(|value| Value(value.to_string())),
+            attributes: vec![],
+            kind: PhantomData,
+        }
     }
 
-    fn value(&self) -> String {
-        "".into()
+    /// Set an attribute, replacing any previous value.
+    fn set(mut self, attribute: impl Attribute + 'static) -> Self {
+        self.attributes
+            .retain(|attr| attr.name() != attribute.name());
+        self.attributes.push(Box::new(attribute));
+        self
+    }
+
+    fn other(self, name: &'static str, value: impl fmt::Display) -> Self {
+        self.set(Other {
+            name,
+            value: value.to_string(),
+        })
+    }
+
+    /// Set the name, used as the key when the form is submitted.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(Name(name.to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn disabled(self) -> Self {
+        self.set(Flag("disabled"))
+    }
+
+    /// Associate the input with a form elsewhere in the document, by the form's id.
+    #[must_use]
+    pub fn form(self, form_id: &str) -> Self {
+        self.other("form", form_id)
     }
 }
 
-/// Input.
-#[derive(Debug, Clone)]
-pub struct Input {
-    type_: InputType,
-    name: Option<Name>,
-    checked: Option<Checked>,
-    value: Value,
-    // TODO: Missing stuff:
-    //  * size
-    //  * autocorrect
-    //  * spellcheck
-    //  * autocapitalize
-    //  * autcomplete
+impl<K: kind::Valued> Input<K> {
+    /// Set the value, e.g. to fill in what the user previously submitted.
+    #[must_use]
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(Value(value.to_string()));
+        self
+    }
+}
+
+impl<K: kind::Autocomplete> Input<K> {
+    #[must_use]
+    pub fn autocomplete(self, autofill: Autofill) -> Self {
+        self.other("autocomplete", autofill.as_str())
+    }
+}
+
+impl<K: kind::Checkable> Input<K> {
+    /// Make this input have the `checked` attribute.
+    pub const fn set_checked(&mut self) {
+        self.checked = Some(Flag("checked"));
+    }
+
+    /// Set whether the input is checked.
+    #[must_use]
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked.then_some(Flag("checked"));
+        self
+    }
+}
+
+impl<K: kind::TextLike> Input<K> {
+    #[must_use]
+    pub fn minlength(self, length: usize) -> Self {
+        self.other("minlength", length)
+    }
+
+    #[must_use]
+    pub fn maxlength(self, length: usize) -> Self {
+        self.other("maxlength", length)
+    }
+
+    /// A regular expression the whole value must match.
+    #[must_use]
+    pub fn pattern(self, pattern: &str) -> Self {
+        self.other("pattern", pattern)
+    }
+
+    /// The width of the input, in characters.
+    #[must_use]
+    pub fn size(self, size: usize) -> Self {
+        self.other("size", size)
+    }
+}
+
+impl<K: kind::Placeholder> Input<K> {
+    #[must_use]
+    pub fn placeholder(self, placeholder: &str) -> Self {
+        self.other("placeholder", placeholder)
+    }
+}
+
+impl<K: kind::Readonly> Input<K> {
+    #[must_use]
+    pub fn readonly(self) -> Self {
+        self.set(Flag("readonly"))
+    }
+}
+
+impl<K: kind::Required> Input<K> {
+    #[must_use]
+    pub fn required(self) -> Self {
+        self.set(Flag("required"))
+    }
+}
+
+impl<K: kind::List> Input<K> {
+    /// Suggest values from the `<datalist>` with the given id.
+    #[must_use]
+    pub fn list(self, datalist_id: &str) -> Self {
+        self.other("list", datalist_id)
+    }
+}
+
+impl<K: kind::Multiple> Input<K> {
+    #[must_use]
+    pub fn multiple(self) -> Self {
+        self.set(Flag("multiple"))
+    }
+}
+
+impl<K: kind::Ranged> Input<K> {
+    #[must_use]
+    pub fn min(self, min: impl Into<K::Bound>) -> Self {
+        self.other("min", min.into())
+    }
+
+    #[must_use]
+    pub fn max(self, max: impl Into<K::Bound>) -> Self {
+        self.other("max", max.into())
+    }
+
+    /// The granularity of the value,
+    /// in seconds for times and days, weeks or months for dates.
+    #[must_use]
+    pub fn step(self, step: f64) -> Self {
+        self.other("step", step)
+    }
+
+    /// Allow any value, without granularity.
+    #[must_use]
+    pub fn step_any(self) -> Self {
+        self.other("step", "any")
+    }
+}
+
+impl<K: kind::Submits> Input<K> {
+    /// Submit to this URL instead of the form's action.
+    #[must_use]
+    pub fn formaction<U: Into<SafeUrl>>(self, url: U) -> Self {
+        self.other("formaction", url.into())
+    }
+
+    /// Submit with this method instead of the form's.
+    #[must_use]
+    pub fn formmethod(self, method: Method) -> Self {
+        self.other("formmethod", method.as_str())
+    }
+
+    /// Encode the data like this instead of the form's `enctype`.
+    #[must_use]
+    pub fn formenctype(self, enctype: Enctype) -> Self {
+        self.other("formenctype", enctype.as_str())
+    }
+
+    /// Skip the browser's validation of the inputs, e.g. for saving a draft.
+    #[must_use]
+    pub fn formnovalidate(self) -> Self {
+        self.set(Flag("formnovalidate"))
+    }
+}
+
+impl Input<kind::File> {
+    /// The file types accepted, e.g. `image/*` or `.pdf`.
+    #[must_use]
+    pub fn accept(self, types: &[&str]) -> Self {
+        self.other("accept", types.join(","))
+    }
+}
+
+impl Input<kind::Image> {
+    /// The size of the image.
+    #[must_use]
+    pub fn dimensions(self, width: usize, height: usize) -> Self {
+        self.other("width", width).other("height", height)
+    }
 }
 
 impl Input {
     #[must_use]
     pub fn text(name: &str, value: &str) -> Self {
-        Self {
-            type_: InputType::Text,
-            name: Some(Name(name.to_string())),
-            value: Value(value.to_string()),
-            checked: None,
-        }
+        Self::new(Some(name), Some(value))
     }
 
     #[must_use]
-    pub fn hidden(name: &str, value: &str) -> Self {
-        Self {
-            type_: InputType::Hidden,
-            name: Some(Name(name.to_string())),
-            value: Value(value.to_string()),
-            checked: None,
-        }
+    pub fn hidden(name: &str, value: &str) -> Input<kind::Hidden> {
+        Input::new(Some(name), Some(value))
     }
 
     #[must_use]
-    pub fn submit(value: &str) -> Self {
-        Self {
-            type_: InputType::Submit,
-            name: None,
-            value: Value(value.to_string()),
-            checked: None,
-        }
+    pub fn submit(value: &str) -> Input<kind::Submit> {
+        Input::new(None, Some(value))
     }
 
     /// The value of this radio input element,
     /// and the name of the group it belongs to.
     #[must_use]
-    pub fn radio(value: &str, name: &str) -> Self {
-        Self {
-            type_: InputType::Radio,
-            name: Some(Name(name.to_string())),
-            value: Value(value.to_string()),
-            checked: None,
-        }
+    pub fn radio(value: &str, name: &str) -> Input<kind::Radio> {
+        Input::new(Some(name), Some(value))
     }
 
-    /// Make this input have the `checked` attribute.
-    pub fn set_checked(&mut self) {
-        self.checked = Some(Checked);
+    /// A checkbox, submitting the value under the name when checked.
+    #[must_use]
+    pub fn checkbox(name: &str, value: &str) -> Input<kind::Checkbox> {
+        Input::new(Some(name), Some(value))
+    }
+
+    #[must_use]
+    pub fn search(name: &str) -> Input<kind::Search> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn url(name: &str) -> Input<kind::Url> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn tel(name: &str) -> Input<kind::Tel> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn email(name: &str) -> Input<kind::Email> {
+        Input::new(Some(name), None)
+    }
+
+    /// A password. Note that the value is sent to the browser if set.
+    #[must_use]
+    pub fn password(name: &str) -> Input<kind::Password> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn number(name: &str) -> Input<kind::Number> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn range(name: &str) -> Input<kind::Range> {
+        Input::new(Some(name), None)
+    }
+
+    /// A date, with values such as `2022-04-01`.
+    #[must_use]
+    pub fn date(name: &str) -> Input<kind::Date> {
+        Input::new(Some(name), None)
+    }
+
+    /// A time, with values such as `13:37`.
+    #[must_use]
+    pub fn time(name: &str) -> Input<kind::Time> {
+        Input::new(Some(name), None)
+    }
+
+    /// A date and time without time zone, with values such as `2022-04-01T13:37`.
+    #[must_use]
+    pub fn datetime_local(name: &str) -> Input<kind::DatetimeLocal> {
+        Input::new(Some(name), None)
+    }
+
+    /// A month, with values such as `2022-04`.
+    #[must_use]
+    pub fn month(name: &str) -> Input<kind::Month> {
+        Input::new(Some(name), None)
+    }
+
+    /// A week, with values such as `2022-W13`.
+    #[must_use]
+    pub fn week(name: &str) -> Input<kind::Week> {
+        Input::new(Some(name), None)
+    }
+
+    /// A color, with values such as `#ff6600`.
+    #[must_use]
+    pub fn color(name: &str) -> Input<kind::Color> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn file(name: &str) -> Input<kind::File> {
+        Input::new(Some(name), None)
+    }
+
+    #[must_use]
+    pub fn reset(value: &str) -> Input<kind::Reset> {
+        Input::new(None, Some(value))
+    }
+
+    /// A button without default behaviour, e.g. for scripts to handle.
+    #[must_use]
+    pub fn button(value: &str) -> Input<kind::Button> {
+        Input::new(None, Some(value))
+    }
+
+    /// A submit button showing an image.
+    #[must_use]
+    pub fn image<U: Into<SafeUrl>>(src: U, alt: &str) -> Input<kind::Image> {
+        Input::new(None, None).set(Src::new(src)).other("alt", alt)
     }
 }
 
-impl Tag for Input {
+impl<K: Kind> Tag for Input<K> {
     fn name(&self) -> &'static str {
         "input"
     }
@@ -145,7 +620,11 @@ impl Tag for Input {
             attrs.push(checked);
         }
 
-        attrs.push(&self.value);
+        if let Some(value) = &self.value {
+            attrs.push(value);
+        }
+
+        attrs.extend(self.attributes.iter().map(AsRef::as_ref));
 
         Some(attrs)
     }
@@ -154,3 +633,126 @@ impl Tag for Input {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::document_tree::o;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn render<K: Kind>(input: Input<K>) -> String {
+        o(input).render_string().unwrap()
+    }
+
+    #[test]
+    fn render_existing_kinds() {
+        let mut radio = Input::radio("dark", "theme");
+        radio.set_checked();
+
+        assert_eq!(
+            r#"<input type="radio" name="theme" checked="" value="dark">"#,
+            render(radio)
+        );
+        assert_eq!(
+            r#"<input type="text" name="q" value="&quot;rust&quot;">"#,
+            render(Input::text("q", "\"rust\""))
+        );
+        assert_eq!(
+            r#"<input type="submit" value="add comment">"#,
+            render(Input::submit("add comment"))
+        );
+    }
+
+    #[test]
+    fn render_text_like() {
+        let email = Input::email("email")
+            .required()
+            .multiple()
+            .placeholder("you@example.com")
+            .maxlength(254)
+            .autocomplete(Autofill::Email)
+            .list("known-emails");
+
+        assert_eq!(
+            r#"<input type="email" name="email" required="" multiple="" placeholder="you@example.com" maxlength="254" autocomplete="email" list="known-emails">"#,
+            render(email)
+        );
+
+        let password = Input::password("password")
+            .minlength(12)
+            .pattern("[^ ]+")
+            .autocomplete(Autofill::NewPassword);
+
+        assert_eq!(
+            r#"<input type="password" name="password" minlength="12" pattern="[^ ]+" autocomplete="new-password">"#,
+            render(password)
+        );
+    }
+
+    #[test]
+    fn render_ranged() {
+        assert_eq!(
+            r#"<input type="number" name="age" min="0" max="150" step="1">"#,
+            render(Input::number("age").min(0).max(150).step(1.0))
+        );
+        assert_eq!(
+            r#"<input type="range" name="volume" value="0.5" min="0" max="1" step="any">"#,
+            render(Input::range("volume").min(0).max(1).step_any().value("0.5"))
+        );
+        assert_eq!(
+            r#"<input type="date" name="from" min="2022-01-01" max="2022-12-31" readonly="">"#,
+            render(
+                Input::date("from")
+                    .min("2022-01-01")
+                    .max("2022-12-31")
+                    .readonly()
+            )
+        );
+    }
+
+    #[test]
+    fn render_other_kinds() {
+        assert_eq!(
+            r#"<input type="checkbox" name="remember" checked="" value="yes" disabled="" form="login">"#,
+            render(
+                Input::checkbox("remember", "yes")
+                    .checked(true)
+                    .disabled()
+                    .form("login")
+            )
+        );
+        assert_eq!(
+            r#"<input type="file" name="avatar" accept="image/*,.pdf" multiple="" required="">"#,
+            render(
+                Input::file("avatar")
+                    .accept(&["image/*", ".pdf"])
+                    .multiple()
+                    .required()
+            )
+        );
+        assert_eq!(
+            r#"<input type="image" src="/go.png" alt="Go" width="20" height="10">"#,
+            render(Input::image("/go.png", "Go").dimensions(20, 10))
+        );
+        assert_eq!(
+            r##"<input type="color" name="accent" value="#ff6600">"##,
+            render(Input::color("accent").value("#ff6600"))
+        );
+
+        for (input, type_) in [
+            (render(Input::search("q")), "search"),
+            (render(Input::url("homepage")), "url"),
+            (render(Input::tel("phone")), "tel"),
+            (render(Input::time("at")), "time"),
+            (render(Input::datetime_local("at")), "datetime-local"),
+            (render(Input::month("in")), "month"),
+            (render(Input::week("in")), "week"),
+            (render(Input::reset("Reset")), "reset"),
+            (render(Input::button("Click")), "button"),
+            (render(Input::hidden("id", "1")), "hidden"),
+        ] {
+            assert!(input.starts_with(&format!(r#"<input type="{type_}""#)));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::attributes::{datetime::Datetime, href::SafeUrl};
+use crate::global_attributes::Attribute;
+
+use super::{q::CiteUrl, Tag};
+
+/// Attributes describing an edit, shared by [`Ins`] and [`super::Del`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Edit {
+    cite: Option<CiteUrl>,
+    datetime: Option<Datetime>,
+}
+
+impl Edit {
+    pub(crate) fn cite<U: Into<SafeUrl>>(&mut self, url: U) {
+        self.cite = Some(CiteUrl::new(url));
+    }
+
+    pub(crate) fn datetime(&mut self, datetime: &str) {
+        self.datetime = Some(Datetime(datetime.to_string()));
+    }
+
+    pub(crate) fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = Vec::new();
+
+        if let Some(cite) = &self.cite {
+            attrs.push(cite);
+        }
+        if let Some(datetime) = &self.datetime {
+            attrs.push(datetime);
+        }
+
+        (!attrs.is_empty()).then_some(attrs)
+    }
+}
+
+/// Content added to the document, usually underlined.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ins).
+#[derive(Debug, Clone, Default)]
+pub struct Ins(Edit);
+
+impl Ins {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A URL explaining the change, e.g. a changelog entry.
+    #[must_use]
+    pub fn cite<U: Into<SafeUrl>>(mut self, url: U) -> Self {
+        self.0.cite(url);
+        self
+    }
+
+    /// When the change was made, e.g. `2024-05-01` or `2024-05-01T12:00Z`.
+    #[must_use]
+    pub fn datetime(mut self, datetime: &str) -> Self {
+        self.0.datetime(datetime);
+        self
+    }
+}
+
+impl Tag for Ins {
+    fn name(&self) -> &'static str {
+        "ins"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.0.attributes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{document_tree::o, science_lab::NodeExt, tags::Del};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_edits() {
+        assert_eq!(
+            "<ins>new</ins>",
+            Ins::new().text("new").render_string().unwrap()
+        );
+        assert_eq!(
+            r#"<p><del cite="/changelog#v2" datetime="2024-05-01">$10</del><ins datetime="2024-05-01T12:00Z">$8</ins></p>"#,
+            o(crate::tags::P)
+                .kid(
+                    Del::new()
+                        .datetime("2024-05-01")
+                        .cite("/changelog#v2")
+                        .text("$10")
+                )
+                .kid(Ins::new().datetime("2024-05-01T12:00Z").text("$8"))
+                .render_string()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Keyboard input, e.g. `Ctrl` + `C`.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/kbd).
+#[derive(Debug, Clone)]
+pub struct Kbd;
+
+impl Tag for Kbd {
+    fn name(&self) -> &'static str {
+        "kbd"
+    }
+}
@@ -0,0 +1,28 @@
+use super::Tag;
+
+/// The caption of a [`super::Fieldset`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/legend).
+#[derive(Debug, Clone)]
+pub struct Legend;
+
+impl Tag for Legend {
+    fn name(&self) -> &'static str {
+        "legend"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_legend() {
+        assert_eq!(
+            "<legend>Shipping</legend>",
+            Legend.text("Shipping").render_string().unwrap()
+        );
+    }
+}
@@ -1,9 +1,22 @@
-use super::Tag;
+use crate::{document_tree::Node, science_lab::NodeExt};
+
+use super::{ol::Number, Tag};
 
 /// Li.
 #[derive(Debug, Clone)]
 pub struct Li;
 
+impl Li {
+    /// An item of an [`super::Ol`] numbered `value`, with the items after it continuing from there.
+    #[must_use]
+    pub fn with_value(value: i64) -> Node {
+        Self.into_node().add_attr(Number {
+            name: "value",
+            value,
+        })
+    }
+}
+
 impl Tag for Li {
     fn name(&self) -> &'static str {
         "li"
@@ -1,3 +1,7 @@
+use std::{fmt, io, path::Path};
+
+use crate::attributes::href::SafeUrl;
+use crate::attributes::integrity::{Algorithm, CrossOrigin, Integrity};
 use crate::global_attributes::Attribute;
 
 use super::Tag;
@@ -73,20 +77,20 @@ impl Attribute for Mime {
     }
 }
 
-/// Href
+/// Href, see [`SafeUrl`].
 #[derive(Debug, Clone)]
-pub struct Href(String);
+pub struct Href(SafeUrl);
 
-impl From<url::Url> for Mime {
-    fn from(val: url::Url) -> Self {
-        Self(val.to_string())
+impl Href {
+    #[must_use]
+    pub fn new<U: Into<SafeUrl>>(url: U) -> Self {
+        Self(url.into())
     }
 }
 
-impl Href {
-    #[must_use]
-    pub fn new(url: &str) -> Self {
-        Self(url.to_string())
+impl From<url::Url> for Href {
+    fn from(val: url::Url) -> Self {
+        Self(val.into())
     }
 }
 
@@ -95,12 +99,23 @@ impl Attribute for Href {
         "href"
     }
 
-    // TODO: Let's see how far this gets us
     fn value(&self) -> String {
-        self.0.to_string()
+        self.0.value()
     }
 }
 
+/// Setting the integrity of a link which browsers do not check, see [`Link::integrity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegrityNotChecked;
+
+impl fmt::Display for IntegrityNotChecked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "browsers only check the integrity of stylesheet links")
+    }
+}
+
+impl std::error::Error for IntegrityNotChecked {}
+
 /// Link.
 /// See [reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Link_types).
 #[derive(Debug, Clone)]
@@ -109,6 +124,8 @@ pub enum Link {
         rel: Rel,
         mime: Mime,
         href: Href,
+        integrity: Option<Integrity>,
+        crossorigin: Option<CrossOrigin>,
     },
     Icon {
         rel: Rel,
@@ -123,28 +140,79 @@ pub enum Link {
 }
 
 impl Link {
-    pub fn stylesheet<M>(mime: M, url: &str) -> Self
+    #[must_use]
+    pub fn stylesheet<M, U>(mime: M, url: U) -> Self
     where
         M: Into<Mime>,
+        U: Into<SafeUrl>,
     {
         Self::Stylesheet {
             rel: Rel::Stylesheet,
             mime: mime.into(),
             href: Href::new(url),
+            integrity: None,
+            crossorigin: None,
+        }
+    }
+
+    /// A stylesheet served from a local file, with its [`Integrity`] computed from that file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read.
+    pub fn stylesheet_vendored<P, U>(path: P, url: U) -> Result<Self, io::Error>
+    where
+        P: AsRef<Path>,
+        U: Into<SafeUrl>,
+    {
+        let integrity = Integrity::of_file(Algorithm::default(), path)?;
+
+        Ok(Self::Stylesheet {
+            rel: Rel::Stylesheet,
+            mime: mime::TEXT_CSS.into(),
+            href: Href::new(url),
+            integrity: Some(integrity),
+            crossorigin: None,
+        })
+    }
+
+    /// Set the stylesheet's integrity.
+    ///
+    /// # Errors
+    ///
+    /// If the link is not a stylesheet, since browsers only check the integrity of stylesheets.
+    pub fn integrity(mut self, integrity: Integrity) -> Result<Self, IntegrityNotChecked> {
+        if let Self::Stylesheet { integrity: i, .. } = &mut self {
+            *i = Some(integrity);
+            Ok(self)
+        } else {
+            Err(IntegrityNotChecked)
+        }
+    }
+
+    /// Set how the stylesheet is fetched with respect to CORS.
+    /// Other links are unchanged.
+    #[must_use]
+    pub const fn crossorigin(mut self, crossorigin: CrossOrigin) -> Self {
+        if let Self::Stylesheet { crossorigin: c, .. } = &mut self {
+            *c = Some(crossorigin);
         }
+        self
     }
 
     #[must_use]
-    pub fn icon(url: &str) -> Self {
+    pub fn icon<U: Into<SafeUrl>>(url: U) -> Self {
         Self::Icon {
             rel: Rel::Icon,
             href: Href::new(url),
         }
     }
 
-    pub fn alternate<M>(mime: M, title: &str, url: &str) -> Self
+    #[must_use]
+    pub fn alternate<M, U>(mime: M, title: &str, url: U) -> Self
     where
         M: Into<Mime>,
+        U: Into<SafeUrl>,
     {
         Self::Alternate {
             rel: Rel::Alternate,
@@ -162,7 +230,23 @@ impl Tag for Link {
 
     fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
         Some(match &self {
-            Link::Stylesheet { rel, mime, href } => vec![rel, mime, href],
+            Self::Stylesheet {
+                rel,
+                mime,
+                href,
+                integrity,
+                crossorigin,
+            } => {
+                let mut attributes: Vec<&dyn Attribute> = vec![rel, mime, href];
+
+                if let Some(integrity) = integrity {
+                    attributes.push(integrity);
+                }
+                if let Some(crossorigin) = crossorigin {
+                    attributes.push(crossorigin);
+                }
+                attributes
+            }
             Link::Icon { rel, href } => vec![rel, href],
             Link::Alternate {
                 rel,
@@ -208,6 +292,22 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn integrity_only_on_stylesheets() {
+        let integrity = Integrity::new(
+            "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO",
+        )
+        .unwrap();
+
+        assert!(Link::stylesheet(mime::TEXT_CSS, "/a.css")
+            .integrity(integrity.clone())
+            .is_ok());
+        assert_eq!(
+            IntegrityNotChecked,
+            Link::icon("favicon.ico").integrity(integrity).unwrap_err()
+        );
+    }
+
     #[test]
     fn render_alternate() {
         let result = o(Link::alternate(
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Highlighted text, e.g. search matches.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/mark).
+#[derive(Debug, Clone)]
+pub struct Mark;
+
+impl Tag for Mark {
+    fn name(&self) -> &'static str {
+        "mark"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// A list of commands, like [`super::Ul`] but semantically a toolbar.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/menu).
+#[derive(Debug, Clone)]
+pub struct Menu;
+
+impl Tag for Menu {
+    fn name(&self) -> &'static str {
+        "menu"
+    }
+}
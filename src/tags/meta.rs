@@ -46,6 +46,7 @@ impl Attribute for Name {
 #[derive(Debug, Clone)]
 pub enum HttpEquiv {
     Refresh,
+    ContentSecurityPolicy,
 }
 
 impl Attribute for HttpEquiv {
@@ -56,6 +57,7 @@ impl Attribute for HttpEquiv {
     fn value(&self) -> String {
         match self {
             HttpEquiv::Refresh => "refresh",
+            Self::ContentSecurityPolicy => "Content-Security-Policy",
         }
         .into()
     }
@@ -126,6 +128,12 @@ impl Meta {
         Self::HttpEquiv((HttpEquiv::Refresh, how_often.to_string().into()))
     }
 
+    /// Deliver a Content Security Policy in the document, see [`crate::csp::Policy::to_meta`].
+    #[must_use]
+    pub fn content_security_policy(policy: &str) -> Self {
+        Self::HttpEquiv((HttpEquiv::ContentSecurityPolicy, policy.into()))
+    }
+
     #[must_use]
     pub fn viewport(content: &str) -> Self {
         Self::Name((Name::Viewport, content.into()))
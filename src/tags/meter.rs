@@ -0,0 +1,268 @@
+use std::fmt;
+
+use crate::global_attributes::Attribute;
+
+use super::Tag;
+
+/// What an out of range number should have been.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Expected {
+    Between(f64, f64),
+    AboveMin(f64),
+    Positive,
+    Finite,
+}
+
+/// A number given to a [`Meter`] or [`super::Progress`] was out of range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidRange {
+    attribute: &'static str,
+    value: f64,
+    expected: Expected,
+}
+
+impl InvalidRange {
+    /// Check that `min <= value <= max`, which also rejects `NaN`.
+    pub(crate) fn check(
+        attribute: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    ) -> Result<(), Self> {
+        if (min..=max).contains(&value) {
+            Ok(())
+        } else {
+            Err(Self {
+                attribute,
+                value,
+                expected: Expected::Between(min, max),
+            })
+        }
+    }
+
+    /// Check that `value` is neither infinite nor `NaN`, which browsers cannot parse.
+    pub(crate) const fn check_finite(attribute: &'static str, value: f64) -> Result<(), Self> {
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(Self {
+                attribute,
+                value,
+                expected: Expected::Finite,
+            })
+        }
+    }
+
+    /// Check that `max > min`, which also rejects `NaN`.
+    pub(crate) fn check_max(max: f64, min: f64) -> Result<(), Self> {
+        if max > min {
+            Ok(())
+        } else {
+            Err(Self {
+                attribute: "max",
+                value: max,
+                expected: Expected::AboveMin(min),
+            })
+        }
+    }
+
+    /// Check that `max > 0`, which also rejects `NaN`.
+    pub(crate) fn check_positive_max(max: f64) -> Result<(), Self> {
+        if max > 0.0 {
+            Ok(())
+        } else {
+            Err(Self {
+                attribute: "max",
+                value: max,
+                expected: Expected::Positive,
+            })
+        }
+    }
+
+    /// The attribute which was out of range, e.g. `low`.
+    #[must_use]
+    pub const fn attribute(&self) -> &'static str {
+        self.attribute
+    }
+}
+
+impl fmt::Display for InvalidRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is {}, ", self.attribute, self.value)?;
+
+        match self.expected {
+            Expected::Between(min, max) => write!(f, "expected a number from {min} to {max}"),
+            Expected::AboveMin(min) => write!(f, "but must be greater than `min`, which is {min}"),
+            Expected::Positive => write!(f, "expected a positive number"),
+            Expected::Finite => write!(f, "expected a finite number"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidRange {}
+
+/// A numeric attribute of a [`Meter`] or [`super::Progress`].
+#[derive(Debug, Clone)]
+pub(crate) struct Number(pub(crate) &'static str, pub(crate) f64);
+
+impl Attribute for Number {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn value(&self) -> String {
+        self.1.to_string()
+    }
+}
+
+/// A value within a known range, e.g. disk usage.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meter).
+///
+/// The ranges are checked when set, so browsers never have to clamp them:
+///
+/// ```
+/// use html_strong::tags::Meter;
+///
+/// let disk = Meter::new(0.9, 0.0, 1.0)?.low(0.25)?.high(0.75)?.optimum(0.0)?;
+///
+/// assert!(Meter::new(0.9, 0.0, 1.0)?.low(0.5)?.high(0.25).is_err());
+/// # Ok::<(), html_strong::tags::meter::InvalidRange>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Meter {
+    value: Number,
+    min: Number,
+    max: Number,
+    low: Option<Number>,
+    high: Option<Number>,
+    optimum: Option<Number>,
+}
+
+impl Meter {
+    /// A meter at `value`, between `min` and `max`.
+    ///
+    /// # Errors
+    ///
+    /// If `min` or `max` is not finite, `min` is not below `max`, or `value` is not between them.
+    pub fn new(value: f64, min: f64, max: f64) -> Result<Self, InvalidRange> {
+        InvalidRange::check_finite("min", min)?;
+        InvalidRange::check_finite("max", max)?;
+        InvalidRange::check_max(max, min)?;
+        InvalidRange::check("value", value, min, max)?;
+
+        Ok(Self {
+            value: Number("value", value),
+            min: Number("min", min),
+            max: Number("max", max),
+            low: None,
+            high: None,
+            optimum: None,
+        })
+    }
+
+    /// Values below this are low.
+    ///
+    /// # Errors
+    ///
+    /// If `low` is not between `min` and `high`, or `max` if `high` is not set.
+    pub fn low(mut self, low: f64) -> Result<Self, InvalidRange> {
+        let upper = self.high.as_ref().map_or(self.max.1, |high| high.1);
+        InvalidRange::check("low", low, self.min.1, upper)?;
+        self.low = Some(Number("low", low));
+        Ok(self)
+    }
+
+    /// Values above this are high.
+    ///
+    /// # Errors
+    ///
+    /// If `high` is not between `low`, or `min` if `low` is not set, and `max`.
+    pub fn high(mut self, high: f64) -> Result<Self, InvalidRange> {
+        let lower = self.low.as_ref().map_or(self.min.1, |low| low.1);
+        InvalidRange::check("high", high, lower, self.max.1)?;
+        self.high = Some(Number("high", high));
+        Ok(self)
+    }
+
+    /// The best value, which decides whether low or high values are good.
+    ///
+    /// # Errors
+    ///
+    /// If `optimum` is not between `min` and `max`.
+    pub fn optimum(mut self, optimum: f64) -> Result<Self, InvalidRange> {
+        InvalidRange::check("optimum", optimum, self.min.1, self.max.1)?;
+        self.optimum = Some(Number("optimum", optimum));
+        Ok(self)
+    }
+}
+
+impl Tag for Meter {
+    fn name(&self) -> &'static str {
+        "meter"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![&self.value, &self.min, &self.max];
+
+        for number in [&self.low, &self.high, &self.optimum].into_iter().flatten() {
+            attrs.push(number);
+        }
+
+        Some(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn meter_ranges() {
+        assert!(Meter::new(0.5, 1.0, 0.0).is_err());
+        assert_eq!(
+            "`max` is 1, but must be greater than `min`, which is 1",
+            Meter::new(0.5, 1.0, 1.0).unwrap_err().to_string()
+        );
+        assert_eq!("value", Meter::new(2.0, 0.0, 1.0).unwrap_err().attribute());
+        assert!(Meter::new(f64::NAN, 0.0, 1.0).is_err());
+        assert_eq!(
+            "`max` is inf, expected a finite number",
+            Meter::new(0.5, 0.0, f64::INFINITY).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "min",
+            Meter::new(0.5, f64::NEG_INFINITY, 1.0)
+                .unwrap_err()
+                .attribute()
+        );
+
+        let meter = Meter::new(0.5, 0.0, 1.0).unwrap();
+        assert!(meter.clone().low(-0.1).is_err());
+        assert!(meter.clone().high(0.2).unwrap().low(0.3).is_err());
+        assert!(meter.clone().optimum(1.1).is_err());
+        assert_eq!(
+            "`high` is 0.2, expected a number from 0.3 to 1",
+            meter.low(0.3).unwrap().high(0.2).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn render_meter() {
+        let meter = Meter::new(60.0, 0.0, 100.0)
+            .unwrap()
+            .low(20.0)
+            .unwrap()
+            .high(80.0)
+            .unwrap()
+            .optimum(50.0)
+            .unwrap();
+
+        assert_eq!(
+            r#"<meter value="60" min="0" max="100" low="20" high="80" optimum="50">60 %</meter>"#,
+            meter.text("60 %").render_string().unwrap()
+        );
+    }
+}
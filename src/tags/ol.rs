@@ -0,0 +1,171 @@
+use crate::{
+    document_tree::Node,
+    global_attributes::{Attribute, Flag},
+    science_lab::NodeExt,
+};
+
+use super::{Li, Tag};
+
+/// How the items of an [`Ol`] are numbered, rendered as the `type` attribute.
+/// Prefer CSS `list-style-type` unless the numbering is part of the meaning,
+/// e.g. in legal documents referring to item "b".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Numbering {
+    /// 1, 2, 3.
+    #[default]
+    Decimal,
+    /// a, b, c.
+    LowerAlpha,
+    /// A, B, C.
+    UpperAlpha,
+    /// i, ii, iii.
+    LowerRoman,
+    /// I, II, III.
+    UpperRoman,
+}
+
+impl Attribute for Numbering {
+    fn name(&self) -> &'static str {
+        "type"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Decimal => "1",
+            Self::LowerAlpha => "a",
+            Self::UpperAlpha => "A",
+            Self::LowerRoman => "i",
+            Self::UpperRoman => "I",
+        }
+        .into()
+    }
+}
+
+/// A number attribute, such as `start` or `value`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Number {
+    pub(crate) name: &'static str,
+    pub(crate) value: i64,
+}
+
+impl Attribute for Number {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn value(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+/// The ordered list element.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ol).
+#[derive(Debug, Clone, Default)]
+pub struct Ol {
+    start: Option<Number>,
+    reversed: Option<Flag>,
+    numbering: Option<Numbering>,
+}
+
+impl Ol {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of the first item, e.g. `31` on the second page of 30 items.
+    #[must_use]
+    pub const fn start(mut self, start: i64) -> Self {
+        self.start = Some(Number {
+            name: "start",
+            value: start,
+        });
+        self
+    }
+
+    /// Number the items from high to low.
+    #[must_use]
+    pub const fn reversed(mut self) -> Self {
+        self.reversed = Some(Flag("reversed"));
+        self
+    }
+
+    #[must_use]
+    pub const fn numbering(mut self, numbering: Numbering) -> Self {
+        self.numbering = Some(numbering);
+        self
+    }
+
+    /// The list with each item in an `<li>`.
+    ///
+    /// ```
+    /// use html_strong::{science_lab::NodeExt, tags::{Ol, A}};
+    ///
+    /// let stories = [("/item?id=1", "First"), ("/item?id=2", "Second")];
+    /// let links = stories.map(|(href, title)| A::href(href).text(title));
+    ///
+    /// assert_eq!(
+    ///     r#"<ol start="31"><li><a href="/item?id=1">First</a></li><li><a href="/item?id=2">Second</a></li></ol>"#,
+    ///     Ol::new().start(31).with_items(links).render_string().unwrap()
+    /// );
+    /// ```
+    pub fn with_items<I, N>(self, items: I) -> Node
+    where
+        I: IntoIterator<Item = N>,
+        N: NodeExt,
+    {
+        items
+            .into_iter()
+            .fold(self.into_node(), |ol, item| ol.kid(Li.kid(item)))
+    }
+}
+
+impl Tag for Ol {
+    fn name(&self) -> &'static str {
+        "ol"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![];
+
+        if let Some(start) = &self.start {
+            attrs.push(start);
+        }
+        if let Some(reversed) = &self.reversed {
+            attrs.push(reversed);
+        }
+        if let Some(numbering) = &self.numbering {
+            attrs.push(numbering);
+        }
+
+        if attrs.is_empty() {
+            None
+        } else {
+            Some(attrs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document_tree::o;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_ordered_lists() {
+        assert_eq!(
+            r#"<ol start="3" reversed="" type="i"><li>c</li><li value="10">j</li></ol>"#,
+            Ol::new()
+                .numbering(Numbering::LowerRoman)
+                .reversed()
+                .start(3)
+                .kid(Li.text("c"))
+                .kid(Li::with_value(10).text("j"))
+                .render_string()
+                .unwrap()
+        );
+        assert_eq!("<ol></ol>", o(Ol::new()).render_string().unwrap());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::global_attributes::{escape_attribute_value, Attribute, Flag};
+
+use super::Tag;
+
+#[derive(Debug, Clone)]
+struct Label(String);
+
+impl Attribute for Label {
+    fn name(&self) -> &'static str {
+        "label"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// A group of options within a [`super::Select`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/optgroup).
+#[derive(Debug, Clone)]
+pub struct Optgroup {
+    label: Label,
+    disabled: Option<Flag>,
+}
+
+impl Optgroup {
+    #[must_use]
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: Label(label.to_string()),
+            disabled: None,
+        }
+    }
+
+    /// Disable all options in the group.
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.disabled = Some(Flag("disabled"));
+        self
+    }
+}
+
+impl Tag for Optgroup {
+    fn name(&self) -> &'static str {
+        "optgroup"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![&self.label];
+
+        if let Some(disabled) = &self.disabled {
+            attrs.push(disabled);
+        }
+
+        Some(attrs)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::global_attributes::{escape_attribute_value, Attribute, Flag};
+
+use super::Tag;
+
+#[derive(Debug, Clone)]
+struct Value(String);
+
+impl Attribute for Value {
+    fn name(&self) -> &'static str {
+        "value"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Label(String);
+
+impl Attribute for Label {
+    fn name(&self) -> &'static str {
+        "label"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// An option of a [`super::Select`] or [`super::Datalist`], i.e. `<option>`.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/option).
+///
+/// Named such that glob imports of the tags do not shadow [`std::option::Option`].
+#[derive(Debug, Clone)]
+pub struct SelectOption {
+    value: Value,
+    label: Option<Label>,
+    selected: Option<Flag>,
+    disabled: Option<Flag>,
+}
+
+impl SelectOption {
+    /// The value submitted when this option is selected.
+    /// The text shown is given as the node's text, or by [`SelectOption::label`].
+    #[must_use]
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: Value(value.to_string()),
+            label: None,
+            selected: None,
+            disabled: None,
+        }
+    }
+
+    /// The text shown, instead of the node's text.
+    #[must_use]
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(Label(label.to_string()));
+        self
+    }
+
+    /// Set whether the option is initially selected.
+    #[must_use]
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected.then_some(Flag("selected"));
+        self
+    }
+
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.disabled = Some(Flag("disabled"));
+        self
+    }
+}
+
+impl Tag for SelectOption {
+    fn name(&self) -> &'static str {
+        "option"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![&self.value];
+
+        if let Some(label) = &self.label {
+            attrs.push(label);
+        }
+
+        if let Some(selected) = &self.selected {
+            attrs.push(selected);
+        }
+
+        if let Some(disabled) = &self.disabled {
+            attrs.push(disabled);
+        }
+
+        Some(attrs)
+    }
+}
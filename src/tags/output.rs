@@ -0,0 +1,98 @@
+use crate::global_attributes::{escape_attribute_value, Attribute};
+
+use super::Tag;
+
+/// The ids of the elements an output was calculated from.
+#[derive(Debug, Clone)]
+struct For(Vec<String>);
+
+impl Attribute for For {
+    fn name(&self) -> &'static str {
+        "for"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0.join(" "))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Name(String);
+
+impl Attribute for Name {
+    fn name(&self) -> &'static str {
+        "name"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+/// The result of a calculation or user action.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/output).
+#[derive(Debug, Clone)]
+pub struct Output {
+    for_: Option<For>,
+    name: Option<Name>,
+}
+
+impl Output {
+    /// An output calculated from the elements with the given ids.
+    #[must_use]
+    pub fn new(for_ids: &[&str]) -> Self {
+        Self {
+            for_: (!for_ids.is_empty())
+                .then(|| For(for_ids.iter().map(ToString::to_string).collect())),
+            name: None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(Name(name.to_string()));
+        self
+    }
+}
+
+impl Tag for Output {
+    fn name(&self) -> &'static str {
+        "output"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![];
+
+        if let Some(for_) = &self.for_ {
+            attrs.push(for_);
+        }
+
+        if let Some(name) = &self.name {
+            attrs.push(name);
+        }
+
+        Some(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document_tree::o;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_output() {
+        assert_eq!(
+            r#"<output for="a b" name="total"></output>"#,
+            o(Output::new(&["a", "b"]).name("total"))
+                .render_string()
+                .unwrap()
+        );
+        assert_eq!(
+            "<output></output>",
+            o(Output::new(&[])).render_string().unwrap()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::global_attributes::Attribute;
+
+use super::{
+    meter::{InvalidRange, Number},
+    Tag,
+};
+
+/// The progress of a task.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/progress).
+///
+/// The node's text is shown by browsers not supporting it, e.g. `70 %`.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    value: Option<Number>,
+    max: Number,
+}
+
+impl Progress {
+    /// Progress of `value` out of `max`.
+    ///
+    /// # Errors
+    ///
+    /// If `max` is not a finite positive number, or `value` is not between 0 and `max`.
+    pub fn new(value: f64, max: f64) -> Result<Self, InvalidRange> {
+        InvalidRange::check_finite("max", max)?;
+        InvalidRange::check_positive_max(max)?;
+        InvalidRange::check("value", value, 0.0, max)?;
+
+        Ok(Self {
+            value: Some(Number("value", value)),
+            max: Number("max", max),
+        })
+    }
+
+    /// Progress which is not known, e.g. while waiting for a reply.
+    #[must_use]
+    pub const fn indeterminate() -> Self {
+        Self {
+            value: None,
+            max: Number("max", 1.0),
+        }
+    }
+}
+
+impl Tag for Progress {
+    fn name(&self) -> &'static str {
+        "progress"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.value
+            .as_ref()
+            .map(|value| vec![value as &dyn Attribute, &self.max])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document_tree::o;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_progress() {
+        assert_eq!(
+            r#"<progress value="0.7" max="1"></progress>"#,
+            o(Progress::new(0.7, 1.0).unwrap()).render_string().unwrap()
+        );
+        assert_eq!(
+            "<progress></progress>",
+            o(Progress::indeterminate()).render_string().unwrap()
+        );
+        assert!(Progress::new(2.0, 1.0).is_err());
+        assert_eq!(
+            "`max` is 0, expected a positive number",
+            Progress::new(0.0, 0.0).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "`max` is inf, expected a finite number",
+            Progress::new(0.5, f64::INFINITY).unwrap_err().to_string()
+        );
+    }
+}
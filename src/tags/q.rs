@@ -0,0 +1,70 @@
+use crate::attributes::href::SafeUrl;
+use crate::global_attributes::Attribute;
+
+use super::Tag;
+
+/// The source of a quote, or the reason for an edit.
+#[derive(Debug, Clone)]
+pub(crate) struct CiteUrl(SafeUrl);
+
+impl CiteUrl {
+    pub(crate) fn new<U: Into<SafeUrl>>(url: U) -> Self {
+        Self(url.into())
+    }
+}
+
+impl Attribute for CiteUrl {
+    fn name(&self) -> &'static str {
+        "cite"
+    }
+
+    fn value(&self) -> String {
+        self.0.value()
+    }
+}
+
+/// An inline quotation, which browsers add quotation marks to.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/q).
+#[derive(Debug, Clone, Default)]
+pub struct Q {
+    cite: Option<CiteUrl>,
+}
+
+impl Q {
+    /// A quotation from the given source.
+    #[must_use]
+    pub fn cite<U: Into<SafeUrl>>(url: U) -> Self {
+        Self {
+            cite: Some(CiteUrl::new(url)),
+        }
+    }
+}
+
+impl Tag for Q {
+    fn name(&self) -> &'static str {
+        "q"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.cite.as_ref().map(|cite| vec![cite as &dyn Attribute])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::science_lab::NodeExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_q() {
+        assert_eq!(
+            r#"<q cite="https://example.com/talk">Less is more</q>"#,
+            Q::cite("https://example.com/talk")
+                .text("Less is more")
+                .render_string()
+                .unwrap()
+        );
+    }
+}
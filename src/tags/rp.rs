@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Parentheses shown around a [`super::Rt`] by browsers without ruby support.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/rp).
+#[derive(Debug, Clone)]
+pub struct Rp;
+
+impl Tag for Rp {
+    fn name(&self) -> &'static str {
+        "rp"
+    }
+}
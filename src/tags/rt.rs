@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The annotation text within a [`super::Ruby`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/rt).
+#[derive(Debug, Clone)]
+pub struct Rt;
+
+impl Tag for Rt {
+    fn name(&self) -> &'static str {
+        "rt"
+    }
+}
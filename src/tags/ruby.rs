@@ -0,0 +1,40 @@
+use super::Tag;
+
+/// Ruby annotations, e.g. pronunciation of CJK characters, with [`super::Rt`] and [`super::Rp`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ruby).
+#[derive(Debug, Clone)]
+pub struct Ruby;
+
+impl Tag for Ruby {
+    fn name(&self) -> &'static str {
+        "ruby"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        science_lab::NodeExt,
+        tags::{Rp, Rt},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_ruby_annotation() {
+        assert_eq!(
+            "<ruby>漢<rp>(</rp><rt>kan</rt><rp>)</rp>字<rp>(</rp><rt>ji</rt><rp>)</rp></ruby>",
+            Ruby.text("漢")
+                .kid(Rp.text("("))
+                .kid(Rt.text("kan"))
+                .kid(Rp.text(")"))
+                .text("字")
+                .kid(Rp.text("("))
+                .kid(Rt.text("ji"))
+                .kid(Rp.text(")"))
+                .render_string()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Content which is no longer accurate, usually struck through. For edits, see [`super::Del`].
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/s).
+#[derive(Debug, Clone)]
+pub struct S;
+
+impl Tag for S {
+    fn name(&self) -> &'static str {
+        "s"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Sample output of a program.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/samp).
+#[derive(Debug, Clone)]
+pub struct Samp;
+
+impl Tag for Samp {
+    fn name(&self) -> &'static str {
+        "samp"
+    }
+}
@@ -1,25 +1,61 @@
+use std::{io, path::Path};
+
+use crate::attributes::href::SafeUrl;
+use crate::attributes::integrity::{Algorithm, CrossOrigin, Integrity};
 use crate::global_attributes::Attribute;
 
-use super::{Tag, img::Src};
+use super::{img::Src, Tag};
 
 /// Script.
 #[derive(Debug, Clone)]
 pub struct Script {
     src: Option<Src>,
+    integrity: Option<Integrity>,
+    crossorigin: Option<CrossOrigin>,
 }
 
 impl Script {
     #[must_use]
     pub const fn new() -> Self {
-        Self { src: None }
+        Self {
+            src: None,
+            integrity: None,
+            crossorigin: None,
+        }
     }
 
     #[must_use]
-    pub fn src(src: &str) -> Self {
+    pub fn src<U: Into<SafeUrl>>(src: U) -> Self {
         Self {
-            src: Some(Src(src.to_string())),
+            src: Some(Src::new(src)),
+            ..Self::new()
         }
     }
+
+    /// A script served from a local file, with its [`Integrity`] computed from that file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read.
+    pub fn vendored<P, U>(path: P, src: U) -> Result<Self, io::Error>
+    where
+        P: AsRef<Path>,
+        U: Into<SafeUrl>,
+    {
+        Ok(Self::src(src).integrity(Integrity::of_file(Algorithm::default(), path)?))
+    }
+
+    #[must_use]
+    pub fn integrity(mut self, integrity: Integrity) -> Self {
+        self.integrity = Some(integrity);
+        self
+    }
+
+    #[must_use]
+    pub const fn crossorigin(mut self, crossorigin: CrossOrigin) -> Self {
+        self.crossorigin = Some(crossorigin);
+        self
+    }
 }
 
 impl Default for Script {
@@ -34,6 +70,18 @@ impl Tag for Script {
     }
 
     fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
-        self.src.as_ref().map(|src| vec![src as &dyn Attribute])
+        let mut attributes: Vec<&dyn Attribute> = vec![];
+
+        if let Some(src) = &self.src {
+            attributes.push(src);
+        }
+        if let Some(integrity) = &self.integrity {
+            attributes.push(integrity);
+        }
+        if let Some(crossorigin) = &self.crossorigin {
+            attributes.push(crossorigin);
+        }
+
+        Some(attributes)
     }
 }
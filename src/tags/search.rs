@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// A search or filtering form.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/search).
+#[derive(Debug, Clone)]
+pub struct Search;
+
+impl Tag for Search {
+    fn name(&self) -> &'static str {
+        "search"
+    }
+}
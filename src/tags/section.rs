@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// A section of a document, usually with a heading.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/section).
+#[derive(Debug, Clone)]
+pub struct Section;
+
+impl Tag for Section {
+    fn name(&self) -> &'static str {
+        "section"
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    document_tree::Node,
+    global_attributes::{escape_attribute_value, Attribute, Flag},
+    science_lab::NodeExt,
+};
+
+use super::{SelectOption, Tag};
+
+#[derive(Debug, Clone)]
+struct Name(String);
+
+impl Attribute for Name {
+    fn name(&self) -> &'static str {
+        "name"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Size(usize);
+
+impl Attribute for Size {
+    fn name(&self) -> &'static str {
+        "size"
+    }
+
+    fn value(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// A dropdown, or a list box if [`Select::multiple`] or [`Select::size`] is set.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/select).
+///
+/// Its children are [`SelectOption`]s, possibly grouped in [`super::Optgroup`]s.
+/// See [`Select::with_options`] for the common case.
+#[derive(Debug, Clone)]
+pub struct Select {
+    name: Name,
+    multiple: Option<Flag>,
+    size: Option<Size>,
+    required: Option<Flag>,
+    disabled: Option<Flag>,
+}
+
+impl Select {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: Name(name.to_string()),
+            multiple: None,
+            size: None,
+            required: None,
+            disabled: None,
+        }
+    }
+
+    /// Allow selecting several options.
+    #[must_use]
+    pub const fn multiple(mut self) -> Self {
+        self.multiple = Some(Flag("multiple"));
+        self
+    }
+
+    /// The number of rows visible at once.
+    #[must_use]
+    pub const fn size(mut self, rows: usize) -> Self {
+        self.size = Some(Size(rows));
+        self
+    }
+
+    #[must_use]
+    pub const fn required(mut self) -> Self {
+        self.required = Some(Flag("required"));
+        self
+    }
+
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.disabled = Some(Flag("disabled"));
+        self
+    }
+
+    /// Add an option per `(value, label)` pair, with the option whose value is `current` selected.
+    ///
+    /// ```
+    /// use html_strong::tags::Select;
+    ///
+    /// let themes = [("light", "Light"), ("dark", "Dark")];
+    /// let select = Select::new("theme").with_options(themes, "dark");
+    ///
+    /// assert_eq!(
+    ///     r#"<select name="theme"><option value="light">Light</option><option value="dark" selected="">Dark</option></select>"#,
+    ///     select.render_string().unwrap()
+    /// );
+    /// ```
+    pub fn with_options<I, V, L>(self, options: I, current: &str) -> Node
+    where
+        I: IntoIterator<Item = (V, L)>,
+        V: AsRef<str>,
+        L: AsRef<str>,
+    {
+        options
+            .into_iter()
+            .fold(self.into_node(), |select, (value, label)| {
+                let value = value.as_ref();
+                let option = SelectOption::new(value)
+                    .selected(value == current)
+                    .text(escape_attribute_value(label.as_ref()));
+
+                select.kid(option)
+            })
+    }
+}
+
+impl Tag for Select {
+    fn name(&self) -> &'static str {
+        "select"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        let mut attrs: Vec<&dyn Attribute> = vec![&self.name];
+
+        for attr in [&self.multiple, &self.required, &self.disabled]
+            .into_iter()
+            .flatten()
+        {
+            attrs.push(attr);
+        }
+
+        if let Some(size) = &self.size {
+            attrs.push(size);
+        }
+
+        Some(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        tags::{Datalist, Input, Optgroup},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_select_with_groups() {
+        let select = Select::new("city")
+            .multiple()
+            .required()
+            .size(4)
+            .kid(
+                Optgroup::new("Norway")
+                    .kid(SelectOption::new("osl").text("Oslo"))
+                    .kid(SelectOption::new("brg").disabled().text("Bergen")),
+            )
+            .kid(
+                Optgroup::new("Sweden")
+                    .disabled()
+                    .kid(SelectOption::new("sto").label("Stockholm")),
+            );
+
+        assert_eq!(
+            concat!(
+                r#"<select name="city" multiple="" required="" size="4">"#,
+                r#"<optgroup label="Norway"><option value="osl">Oslo</option><option value="brg" disabled="">Bergen</option></optgroup>"#,
+                r#"<optgroup label="Sweden" disabled=""><option value="sto" label="Stockholm"></option></optgroup>"#,
+                "</select>"
+            ),
+            select.render_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn render_with_options_escaped() {
+        let options = vec![
+            ("a&b".to_string(), "<b>A</b>".to_string()),
+            ("c".to_string(), "C".to_string()),
+        ];
+
+        assert_eq!(
+            r#"<select name="x" disabled=""><option value="a&amp;b" selected="">&lt;b&gt;A&lt;/b&gt;</option><option value="c">C</option></select>"#,
+            Select::new("x")
+                .disabled()
+                .with_options(options, "a&b")
+                .render_string()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn render_datalist() {
+        let result = o(Input::text("browser", "").list("browsers"))
+            .render_string()
+            .unwrap()
+            + &Datalist::with_values("browsers", ["Firefox", "Chrome"])
+                .render_string()
+                .unwrap();
+
+        assert_eq!(
+            r#"<input type="text" name="browser" value="" list="browsers"><datalist id="browsers"><option value="Firefox"></option><option value="Chrome"></option></datalist>"#,
+            result
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Side comments and small print, e.g. copyright notices.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/small).
+#[derive(Debug, Clone)]
+pub struct Small;
+
+impl Tag for Small {
+    fn name(&self) -> &'static str {
+        "small"
+    }
+}
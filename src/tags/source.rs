@@ -1,3 +1,4 @@
+use crate::attributes::href::SafeUrl;
 use crate::global_attributes::Attribute;
 
 use super::{img::Src, Tag};
@@ -31,17 +32,19 @@ pub struct Source {
 }
 
 impl Source {
-    pub fn new_webm<S: AsRef<str>>(source: S) -> Self {
+    #[must_use]
+    pub fn new_webm<U: Into<SafeUrl>>(source: U) -> Self {
         Self {
             type_: Type_::Webm,
-            src: Src(source.as_ref().to_string()),
+            src: Src::new(source),
         }
     }
 
-    pub fn new_mp4<S: AsRef<str>>(source: S) -> Self {
+    #[must_use]
+    pub fn new_mp4<U: Into<SafeUrl>>(source: U) -> Self {
         Self {
             type_: Type_::Mp4,
-            src: Src(source.as_ref().to_string()),
+            src: Src::new(source),
         }
     }
 }
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Strong importance, usually bold.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/strong).
+#[derive(Debug, Clone)]
+pub struct Strong;
+
+impl Tag for Strong {
+    fn name(&self) -> &'static str {
+        "strong"
+    }
+}
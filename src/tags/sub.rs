@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Subscript, e.g. the 2 in H₂O.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/sub).
+#[derive(Debug, Clone)]
+pub struct Sub;
+
+impl Tag for Sub {
+    fn name(&self) -> &'static str {
+        "sub"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// The always visible label of a [`super::Details`], which toggles it when clicked.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/summary).
+#[derive(Debug, Clone)]
+pub struct Summary;
+
+impl Tag for Summary {
+    fn name(&self) -> &'static str {
+        "summary"
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Superscript, e.g. the 2 in x².
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/sup).
+#[derive(Debug, Clone)]
+pub struct Sup;
+
+impl Tag for Sup {
+    fn name(&self) -> &'static str {
+        "sup"
+    }
+}
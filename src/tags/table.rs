@@ -9,3 +9,64 @@ impl Tag for Table {
         "table"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_tree::o,
+        science_lab::NodeExt,
+        tags::{
+            td::{td, Colspan},
+            th::Scope,
+            Caption, Col, Colgroup, Tbody, Td, Tfoot, Th, Thead, Tr,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_table_model() {
+        let table = Table
+            .kid(Caption.text("Sales"))
+            .kid(
+                Colgroup::default()
+                    .kid(Col::default())
+                    .kid(Col::span(2).unwrap()),
+            )
+            .kid(
+                Thead.kid(
+                    Tr.kid(Th::scope(Scope::Col).text("Region")).kid(
+                        Th::scope(Scope::Col)
+                            .abbr("Q1")
+                            .with_colspan(Colspan::new(2).unwrap())
+                            .text("First quarter"),
+                    ),
+                ),
+            )
+            .kid(
+                Tbody.kid(
+                    Tr.kid(
+                        Th::rowspan(2)
+                            .unwrap()
+                            .with_scope(Scope::Rowgroup)
+                            .text("North"),
+                    )
+                    .kid(o(td().headers(&["q1", "north"])).text("1")),
+                ),
+            )
+            .kid(Tfoot.kid(Tr.kid(o(Td::colspan(3).unwrap()).text("Total"))));
+
+        assert_eq!(
+            concat!(
+                "<table><caption>Sales</caption>",
+                r#"<colgroup><col><col span="2"></colgroup>"#,
+                r#"<thead><tr><th scope="col">Region</th><th colspan="2" scope="col" abbr="Q1">First quarter</th></tr></thead>"#,
+                r#"<tbody><tr><th rowspan="2" scope="rowgroup">North</th><td headers="q1 north">1</td></tr></tbody>"#,
+                r#"<tfoot><tr><td colspan="3">Total</td></tr></tfoot>"#,
+                "</table>"
+            ),
+            table.render_string().unwrap()
+        );
+    }
+}
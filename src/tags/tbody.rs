@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Table body, the rows of data.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/tbody).
+#[derive(Debug, Clone)]
+pub struct Tbody;
+
+impl Tag for Tbody {
+    fn name(&self) -> &'static str {
+        "tbody"
+    }
+}
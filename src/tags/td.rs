@@ -1,11 +1,69 @@
-use std::num::NonZeroUsize;
+use std::fmt;
 
-use crate::global_attributes::Attribute;
+use crate::global_attributes::{escape_attribute_value, Attribute};
 
 use super::Tag;
 
+/// A span was outside the range allowed by HTML, see [`Colspan::new`] and [`Rowspan::new`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSpan {
+    attribute: &'static str,
+    span: usize,
+    min: usize,
+    max: usize,
+}
+
+impl InvalidSpan {
+    /// Check that `min <= span <= max`.
+    pub(crate) const fn check(
+        attribute: &'static str,
+        span: usize,
+        min: usize,
+        max: usize,
+    ) -> Result<usize, Self> {
+        if span >= min && span <= max {
+            Ok(span)
+        } else {
+            Err(Self {
+                attribute,
+                span,
+                min,
+                max,
+            })
+        }
+    }
+}
+
+impl fmt::Display for InvalidSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is {}, expected {} to {}",
+            self.attribute, self.span, self.min, self.max
+        )
+    }
+}
+
+impl std::error::Error for InvalidSpan {}
+
+/// The number of columns a cell spans.
 #[derive(Debug, Clone)]
-pub struct Colspan(pub(crate) NonZeroUsize);
+pub struct Colspan(usize);
+
+impl Colspan {
+    /// The most columns a cell may span.
+    pub const MAX: usize = 1000;
+
+    /// # Errors
+    ///
+    /// If `span` is not from 1 to [`Colspan::MAX`].
+    pub const fn new(span: usize) -> Result<Self, InvalidSpan> {
+        match InvalidSpan::check("colspan", span, 1, Self::MAX) {
+            Ok(span) => Ok(Self(span)),
+            Err(invalid) => Err(invalid),
+        }
+    }
+}
 
 impl Attribute for Colspan {
     fn name(&self) -> &'static str {
@@ -17,6 +75,55 @@ impl Attribute for Colspan {
     }
 }
 
+/// The number of rows a cell spans, where 0 means the rest of its row group.
+#[derive(Debug, Clone)]
+pub struct Rowspan(usize);
+
+impl Rowspan {
+    /// The most rows a cell may span.
+    pub const MAX: usize = 65534;
+
+    /// # Errors
+    ///
+    /// If `span` is above [`Rowspan::MAX`].
+    pub const fn new(span: usize) -> Result<Self, InvalidSpan> {
+        match InvalidSpan::check("rowspan", span, 0, Self::MAX) {
+            Ok(span) => Ok(Self(span)),
+            Err(invalid) => Err(invalid),
+        }
+    }
+}
+
+impl Attribute for Rowspan {
+    fn name(&self) -> &'static str {
+        "rowspan"
+    }
+
+    fn value(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The ids of the header cells which apply to a cell.
+#[derive(Debug, Clone)]
+pub(crate) struct Headers(Vec<String>);
+
+impl Headers {
+    pub(crate) fn new(ids: &[&str]) -> Self {
+        Self(ids.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl Attribute for Headers {
+    fn name(&self) -> &'static str {
+        "headers"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0.join(" "))
+    }
+}
+
 /// Shortcut for [`Td::default()`].
 #[must_use]
 pub fn td() -> Td {
@@ -24,17 +131,50 @@ pub fn td() -> Td {
 }
 
 /// Table data cell.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/td).
 #[derive(Debug, Clone, Default)]
 pub struct Td {
     colspan: Option<Colspan>,
+    rowspan: Option<Rowspan>,
+    headers: Option<Headers>,
 }
 
 impl Td {
+    /// A cell spanning several columns.
+    ///
+    /// # Errors
+    ///
+    /// If `span` is not from 1 to [`Colspan::MAX`].
+    pub fn colspan(span: usize) -> Result<Self, InvalidSpan> {
+        Ok(Self::default().with_colspan(Colspan::new(span)?))
+    }
+
+    /// A cell spanning several rows.
+    ///
+    /// # Errors
+    ///
+    /// If `span` is above [`Rowspan::MAX`].
+    pub fn rowspan(span: usize) -> Result<Self, InvalidSpan> {
+        Ok(Self::default().with_rowspan(Rowspan::new(span)?))
+    }
+
     #[must_use]
-    pub fn colspan(span: usize) -> Self {
-        Self {
-            colspan: Some(Colspan(span.try_into().expect("Must be non-zero"))),
-        }
+    pub const fn with_colspan(mut self, colspan: Colspan) -> Self {
+        self.colspan = Some(colspan);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_rowspan(mut self, rowspan: Rowspan) -> Self {
+        self.rowspan = Some(rowspan);
+        self
+    }
+
+    /// The ids of the header cells for this cell, for tables too complex for [`super::th::Scope`].
+    #[must_use]
+    pub fn headers(mut self, ids: &[&str]) -> Self {
+        self.headers = Some(Headers::new(ids));
+        self
     }
 }
 
@@ -49,6 +189,12 @@ impl Tag for Td {
         if let Some(colspan) = &self.colspan {
             attrs.push(colspan);
         }
+        if let Some(rowspan) = &self.rowspan {
+            attrs.push(rowspan);
+        }
+        if let Some(headers) = &self.headers {
+            attrs.push(headers);
+        }
 
         if attrs.is_empty() {
             None
@@ -57,3 +203,25 @@ impl Tag for Td {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tags::{Col, Th};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn span_limits() {
+        assert!(Td::colspan(0).is_err());
+        assert!(Td::colspan(Colspan::MAX).is_ok());
+        assert!(Th::colspan(Colspan::MAX + 1).is_err());
+        assert!(Td::rowspan(0).is_ok());
+        assert!(Th::rowspan(Rowspan::MAX).is_ok());
+        assert!(Col::span(0).is_err());
+        assert_eq!(
+            "`rowspan` is 65535, expected 0 to 65534",
+            Td::rowspan(Rowspan::MAX + 1).unwrap_err().to_string()
+        );
+    }
+}
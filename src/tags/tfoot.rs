@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Table foot, e.g. rows of totals.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/tfoot).
+#[derive(Debug, Clone)]
+pub struct Tfoot;
+
+impl Tag for Tfoot {
+    fn name(&self) -> &'static str {
+        "tfoot"
+    }
+}
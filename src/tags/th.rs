@@ -1,26 +1,121 @@
-use crate::global_attributes::Attribute;
+use crate::global_attributes::{escape_attribute_value, Attribute};
 
-use super::td::Colspan;
+use super::td::{Colspan, Headers, InvalidSpan, Rowspan};
 use super::Tag;
 
+/// The cells a header cell applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Row,
+    Col,
+    /// The rest of the row group, e.g. a [`super::Tbody`].
+    Rowgroup,
+    /// The rest of the column group, see [`super::Colgroup`].
+    Colgroup,
+}
+
+impl Attribute for Scope {
+    fn name(&self) -> &'static str {
+        "scope"
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Row => "row",
+            Self::Col => "col",
+            Self::Rowgroup => "rowgroup",
+            Self::Colgroup => "colgroup",
+        }
+        .into()
+    }
+}
+
+/// A short form of the header, e.g. read by screen readers for each cell.
+#[derive(Debug, Clone)]
+struct Abbr(String);
+
+impl Attribute for Abbr {
+    fn name(&self) -> &'static str {
+        "abbr"
+    }
+
+    fn value(&self) -> String {
+        escape_attribute_value(&self.0)
+    }
+}
+
 /// Shortcut for [`Th::default()`].
 #[must_use]
 pub fn th() -> Th {
     Th::default()
 }
 
-/// Th.
+/// Table header cell.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/th).
 #[derive(Debug, Clone, Default)]
 pub struct Th {
     colspan: Option<Colspan>,
+    rowspan: Option<Rowspan>,
+    headers: Option<Headers>,
+    scope: Option<Scope>,
+    abbr: Option<Abbr>,
 }
 
 impl Th {
+    /// A header spanning several columns.
+    ///
+    /// # Errors
+    ///
+    /// If `span` is not from 1 to [`Colspan::MAX`].
+    pub fn colspan(span: usize) -> Result<Self, InvalidSpan> {
+        Ok(Self::default().with_colspan(Colspan::new(span)?))
+    }
+
+    /// A header spanning several rows.
+    ///
+    /// # Errors
+    ///
+    /// If `span` is above [`Rowspan::MAX`].
+    pub fn rowspan(span: usize) -> Result<Self, InvalidSpan> {
+        Ok(Self::default().with_rowspan(Rowspan::new(span)?))
+    }
+
+    /// A header for the cells in the given direction.
     #[must_use]
-    pub fn colspan(span: usize) -> Self {
-        Self {
-            colspan: Some(Colspan(span.try_into().expect("Must be non-zero"))),
-        }
+    pub fn scope(scope: Scope) -> Self {
+        Self::default().with_scope(scope)
+    }
+
+    #[must_use]
+    pub const fn with_colspan(mut self, colspan: Colspan) -> Self {
+        self.colspan = Some(colspan);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_rowspan(mut self, rowspan: Rowspan) -> Self {
+        self.rowspan = Some(rowspan);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_scope(mut self, scope: Scope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// The ids of the header cells for this header.
+    #[must_use]
+    pub fn headers(mut self, ids: &[&str]) -> Self {
+        self.headers = Some(Headers::new(ids));
+        self
+    }
+
+    /// A short form of the header's text.
+    #[must_use]
+    pub fn abbr(mut self, abbr: &str) -> Self {
+        self.abbr = Some(Abbr(abbr.to_string()));
+        self
     }
 }
 
@@ -35,6 +130,18 @@ impl Tag for Th {
         if let Some(colspan) = &self.colspan {
             attrs.push(colspan);
         }
+        if let Some(rowspan) = &self.rowspan {
+            attrs.push(rowspan);
+        }
+        if let Some(headers) = &self.headers {
+            attrs.push(headers);
+        }
+        if let Some(scope) = &self.scope {
+            attrs.push(scope);
+        }
+        if let Some(abbr) = &self.abbr {
+            attrs.push(abbr);
+        }
 
         if attrs.is_empty() {
             None
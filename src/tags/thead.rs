@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// Table head, the rows of column headers.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/thead).
+#[derive(Debug, Clone)]
+pub struct Thead;
+
+impl Tag for Thead {
+    fn name(&self) -> &'static str {
+        "thead"
+    }
+}
@@ -0,0 +1,283 @@
+use crate::attributes::datetime::Datetime;
+use crate::global_attributes::Attribute;
+
+use super::Tag;
+
+#[cfg(feature = "chrono")]
+use crate::{document_tree::Node, global_attributes::escape_attribute_value, science_lab::NodeExt};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, TimeDelta, TimeZone, Timelike};
+#[cfg(feature = "chrono")]
+use std::fmt::{self, Display, Write};
+
+/// A date, time or duration, readable by both humans and machines.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/time).
+///
+/// With the `chrono` feature, the machine-readable `datetime` attribute
+/// can be built from `chrono` types, see e.g. `Time::relative`.
+#[derive(Debug, Clone, Default)]
+pub struct Time {
+    datetime: Option<Datetime>,
+}
+
+impl Time {
+    /// A time whose text is machine-readable as is, e.g. `2024-05-01`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A time with the given machine-readable value, e.g. `2024-05-01T12:00Z` or `PT2H`.
+    /// The value is not validated, prefer the `chrono` constructors if possible.
+    #[must_use]
+    pub fn raw(datetime: &str) -> Self {
+        Self {
+            datetime: Some(Datetime(datetime.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Time {
+    /// A date, e.g. `2024-05-01`.
+    #[must_use]
+    pub fn date(date: NaiveDate) -> Self {
+        Self::raw(&date.format("%Y-%m-%d").to_string())
+    }
+
+    /// A date and time without a time zone, e.g. `2024-05-01T12:00:00`.
+    #[must_use]
+    pub fn naive(datetime: NaiveDateTime) -> Self {
+        let format = if datetime.nanosecond() == 0 {
+            "%Y-%m-%dT%H:%M:%S"
+        } else {
+            "%Y-%m-%dT%H:%M:%S%.3f"
+        };
+
+        Self::raw(&datetime.format(format).to_string())
+    }
+
+    /// A date and time with its offset, e.g. `2024-05-01T12:00:00Z` or `2024-05-01T14:00:00+02:00`.
+    #[must_use]
+    pub fn datetime<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        // HTML allows at most three fractional digits.
+        let seconds = if datetime.nanosecond() == 0 {
+            SecondsFormat::Secs
+        } else {
+            SecondsFormat::Millis
+        };
+
+        Self::raw(&datetime.to_rfc3339_opts(seconds, true))
+    }
+
+    /// A duration, e.g. `PT1H30M` or `P2DT5S`. Negative durations are made positive.
+    #[must_use]
+    pub fn duration(duration: TimeDelta) -> Self {
+        Self::raw(&html_duration(duration.abs()))
+    }
+
+    /// A date and time with text formatted by `chrono`, e.g. `%e %B %Y` for `1 May 2024`.
+    ///
+    /// # Errors
+    ///
+    /// If the format is not valid, see [`chrono::format::strftime`].
+    pub fn absolute<Tz>(datetime: &DateTime<Tz>, format: &str) -> Result<Node, fmt::Error>
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        let mut text = String::new();
+        write!(text, "{}", datetime.format(format))?;
+
+        Ok(Self::datetime(datetime).text(escape_attribute_value(&text)))
+    }
+
+    /// A date and time with text relative to `now`, e.g. `3 hours ago`, see [`relative_text`].
+    pub fn relative<Tz, Tz2>(datetime: &DateTime<Tz>, now: &DateTime<Tz2>) -> Node
+    where
+        Tz: TimeZone,
+        Tz2: TimeZone,
+    {
+        let elapsed = now.clone().signed_duration_since(datetime);
+
+        Self::datetime(datetime).text(relative_text(elapsed))
+    }
+}
+
+/// How long ago something happened, e.g. `just now`, `1 minute ago` or `3 days ago`.
+/// Negative durations are in the future, e.g. `in 2 hours`.
+///
+/// Months are counted as 30 days and years as 365 days.
+#[cfg(feature = "chrono")]
+#[must_use]
+pub fn relative_text(elapsed: TimeDelta) -> String {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const MONTH: u64 = 30 * DAY;
+    const YEAR: u64 = 365 * DAY;
+
+    let seconds = elapsed.num_seconds();
+    let (count, unit) = match seconds.unsigned_abs() {
+        0..MINUTE => return "just now".into(),
+        s @ MINUTE..HOUR => (s / MINUTE, "minute"),
+        s @ HOUR..DAY => (s / HOUR, "hour"),
+        s @ DAY..MONTH => (s / DAY, "day"),
+        s @ MONTH..YEAR => (s / MONTH, "month"),
+        s => (s / YEAR, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+
+    if seconds < 0 {
+        format!("in {count} {unit}{plural}")
+    } else {
+        format!("{count} {unit}{plural} ago")
+    }
+}
+
+/// A valid duration string, which only has days, hours, minutes and seconds.
+#[cfg(feature = "chrono")]
+fn html_duration(duration: TimeDelta) -> String {
+    let seconds = duration.num_seconds();
+    let millis = duration.subsec_nanos() / 1_000_000;
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds % 86400 / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+    );
+
+    let mut out = String::from("P");
+    if days > 0 {
+        let _ = write!(out, "{days}D");
+    }
+    if days == 0 || hours > 0 || minutes > 0 || seconds > 0 || millis > 0 {
+        out.push('T');
+        if hours > 0 {
+            let _ = write!(out, "{hours}H");
+        }
+        if minutes > 0 {
+            let _ = write!(out, "{minutes}M");
+        }
+        if millis > 0 {
+            let _ = write!(out, "{seconds}.{millis:03}S");
+        } else if seconds > 0 || (hours == 0 && minutes == 0) {
+            let _ = write!(out, "{seconds}S");
+        }
+    }
+
+    out
+}
+
+impl Tag for Time {
+    fn name(&self) -> &'static str {
+        "time"
+    }
+
+    fn attributes(&self) -> Option<Vec<&dyn Attribute>> {
+        self.datetime
+            .as_ref()
+            .map(|datetime| vec![datetime as &dyn Attribute])
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, TimeDelta};
+    use pretty_assertions::assert_eq;
+
+    use crate::document_tree::o;
+
+    use super::*;
+
+    fn datetime(time: Time) -> String {
+        o(time).render_string().unwrap()
+    }
+
+    #[test]
+    fn render_datetime_values() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let noon = date.and_hms_opt(12, 0, 0).unwrap();
+
+        assert_eq!(
+            r#"<time datetime="2024-05-01"></time>"#,
+            datetime(Time::date(date))
+        );
+        assert_eq!(
+            r#"<time datetime="2024-05-01T12:00:00"></time>"#,
+            datetime(Time::naive(noon))
+        );
+        assert_eq!(
+            r#"<time datetime="2024-05-01T12:00:00Z"></time>"#,
+            datetime(Time::datetime(&noon.and_utc()))
+        );
+        assert_eq!(
+            r#"<time datetime="2024-05-01T12:00:00.250+02:00"></time>"#,
+            datetime(Time::datetime(
+                &(noon + TimeDelta::milliseconds(250))
+                    .and_local_timezone(FixedOffset::east_opt(2 * 3600).unwrap())
+                    .unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn render_durations() {
+        let cases = [
+            (TimeDelta::zero(), "PT0S"),
+            (TimeDelta::minutes(90), "PT1H30M"),
+            (TimeDelta::days(2) + TimeDelta::seconds(5), "P2DT5S"),
+            (TimeDelta::days(-1), "P1D"),
+            (TimeDelta::milliseconds(1500), "PT1.500S"),
+        ];
+
+        for (duration, expected) in cases {
+            assert_eq!(
+                format!(r#"<time datetime="{expected}"></time>"#),
+                datetime(Time::duration(duration))
+            );
+        }
+    }
+
+    #[test]
+    fn render_human_text() {
+        let posted = NaiveDate::from_ymd_opt(2024, 5, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc();
+
+        assert_eq!(
+            r#"<time datetime="2024-05-01T12:00:00Z">3 hours ago</time>"#,
+            Time::relative(&posted, &(posted + TimeDelta::minutes(200)))
+                .render_string()
+                .unwrap()
+        );
+        assert_eq!(
+            r#"<time datetime="2024-05-01T12:00:00Z">1 May 2024</time>"#,
+            Time::absolute(&posted, "%-d %B %Y")
+                .unwrap()
+                .render_string()
+                .unwrap()
+        );
+        assert!(Time::absolute(&posted, "%Q").is_err());
+    }
+
+    #[test]
+    fn relative_texts() {
+        let cases = [
+            (TimeDelta::seconds(59), "just now"),
+            (TimeDelta::seconds(-30), "just now"),
+            (TimeDelta::minutes(1), "1 minute ago"),
+            (TimeDelta::hours(23), "23 hours ago"),
+            (TimeDelta::days(1), "1 day ago"),
+            (TimeDelta::days(45), "1 month ago"),
+            (TimeDelta::days(800), "2 years ago"),
+            (TimeDelta::hours(-2), "in 2 hours"),
+        ];
+
+        for (elapsed, expected) in cases {
+            assert_eq!(expected, relative_text(elapsed));
+        }
+    }
+}
@@ -1,11 +1,44 @@
-use super::Tag;
+use crate::{document_tree::Node, science_lab::NodeExt};
+
+use super::{Li, Tag};
 
 /// The unordered list element, Ul. See [here](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ul).
 #[derive(Debug, Clone)]
 pub struct Ul;
 
+impl Ul {
+    /// The list with each item in an `<li>`, see [`super::Ol::with_items`].
+    pub fn with_items<I, N>(self, items: I) -> Node
+    where
+        I: IntoIterator<Item = N>,
+        N: NodeExt,
+    {
+        items
+            .into_iter()
+            .fold(self.into_node(), |ul, item| ul.kid(Li.kid(item)))
+    }
+}
+
 impl Tag for Ul {
     fn name(&self) -> &'static str {
         "ul"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tags::{Span, B};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_ul_from_items() {
+        assert_eq!(
+            "<ul><li><span>a</span></li><li><b>b</b></li></ul>",
+            Ul.with_items([Span.text("a"), B.text("b")])
+                .render_string()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Tag;
+
+/// A variable, e.g. in a mathematical expression.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/var).
+#[derive(Debug, Clone)]
+pub struct Var;
+
+impl Tag for Var {
+    fn name(&self) -> &'static str {
+        "var"
+    }
+}
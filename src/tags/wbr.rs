@@ -0,0 +1,36 @@
+use super::Tag;
+
+/// A position where a line break may be added, e.g. within long URLs.
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/wbr).
+#[derive(Debug, Clone)]
+pub struct Wbr;
+
+impl Tag for Wbr {
+    fn name(&self) -> &'static str {
+        "wbr"
+    }
+
+    fn close_tag(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{science_lab::NodeExt, tags::Span};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_wbr() {
+        assert_eq!(
+            "<span>very<wbr>long</span>",
+            Span.text("very")
+                .kid(Wbr)
+                .text("long")
+                .render_string()
+                .unwrap()
+        );
+    }
+}
@@ -1,11 +1,30 @@
+use std::{io, path::PathBuf};
+
+use crate::css::{Format, Stylesheet};
 use crate::document_tree::{o, Node};
-use crate::tags::{Body, Doctype, Head, Html, Meta};
+use crate::global_attributes::{Lang, Listener};
+use crate::science_lab::NodeExt;
+use crate::tags::{Body, Doctype, Head, Html, Link, Meta, Script};
+
+/// Where the styles of components used in the document end up,
+/// see [`crate::css::ScopedStyle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleMode {
+    /// A single `<style>` element at the end of the head.
+    Inline,
+    /// Written to the file at `path`, and linked in the head via `href`.
+    External { path: PathBuf, href: String },
+}
 
 /// Sets up a default HTML document,
 /// with user settable header and body.
 pub struct HtmlDocumentBuilder {
     head: Option<Node>,
     body: Option<Node>,
+    lang: Option<Lang>,
+    style_mode: StyleMode,
+    css_format: Format,
+    nonce: Option<String>,
 }
 
 /// Creates a [Node] based on the [Head] tag.
@@ -26,6 +45,10 @@ impl HtmlDocumentBuilder {
         Self {
             head: None,
             body: None,
+            lang: None,
+            style_mode: StyleMode::Inline,
+            css_format: Format::Minified,
+            nonce: None,
         }
     }
 
@@ -41,13 +64,101 @@ impl HtmlDocumentBuilder {
         self
     }
 
+    /// Set the document's language on the `<html>` element.
+    /// Right-to-left languages also get `dir="rtl"`.
+    #[must_use]
+    pub fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Choose where collected component styles end up. Defaults to [`StyleMode::Inline`].
+    #[must_use]
+    pub fn with_style_mode(mut self, style_mode: StyleMode) -> Self {
+        self.style_mode = style_mode;
+        self
+    }
+
+    /// Choose how collected component styles are rendered. Defaults to [`Format::Minified`].
+    #[must_use]
+    pub const fn with_css_format(mut self, css_format: Format) -> Self {
+        self.css_format = css_format;
+        self
+    }
+
+    /// Set a nonce on the elements the builder generates,
+    /// i.e. the collected component styles and the script attaching [`Listener`]s.
+    #[must_use]
+    pub fn with_nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    /// Build the document.
+    ///
+    /// # Panics
+    ///
+    /// If writing the stylesheet fails when using [`StyleMode::External`],
+    /// see [`HtmlDocumentBuilder::try_build`].
+    #[must_use]
     pub fn build(self) -> Node {
-        let head = self.head.unwrap_or_else(head);
-        let body = self.body.unwrap_or_else(|| o(Body));
+        self.try_build()
+            .expect("Should be able to write the external stylesheet")
+    }
+
+    /// Build the document.
+    ///
+    /// The styles of components used anywhere in the document are collected,
+    /// deduplicated, and placed according to the [`StyleMode`].
+    /// Event [`Listener`]s used in the body are attached by a script at the end of the body.
+    ///
+    /// # Errors
+    ///
+    /// If writing the stylesheet fails when using [`StyleMode::External`].
+    pub fn try_build(self) -> Result<Node, io::Error> {
+        let mut head = self.head.unwrap_or_else(head);
+        let mut body = self.body.unwrap_or_else(|| o(Body));
+
+        let stylesheet = head
+            .scoped_styles()
+            .into_iter()
+            .chain(body.scoped_styles())
+            .fold(Stylesheet::new(), |stylesheet, style| {
+                stylesheet.extend(style.stylesheet().clone())
+            });
+
+        if !stylesheet.is_empty() {
+            match self.style_mode {
+                StyleMode::Inline => {
+                    let style = stylesheet.to_style(self.css_format);
+
+                    head.push_kid(match &self.nonce {
+                        Some(nonce) => style.nonce(nonce),
+                        None => style,
+                    });
+                }
+                StyleMode::External { path, href } => {
+                    stylesheet.write_file(path, self.css_format)?;
+                    head.push_kid(Link::stylesheet(mime::TEXT_CSS, &href));
+                }
+            }
+        }
 
-        Node::root()
-            .kid(o(Doctype))
-            .kid(o(Html).kid(head).kid(body))
+        let listeners = body.listeners();
+        if !listeners.is_empty() {
+            let script = Script::new().text(Listener::script(&listeners));
+
+            body.push_kid(match &self.nonce {
+                Some(nonce) => script.nonce(nonce),
+                None => script,
+            });
+        }
+
+        let html = self
+            .lang
+            .map_or_else(|| o(Html), |lang| o(Html).set_lang(lang));
+
+        Ok(Node::root().kid(o(Doctype)).kid(html.kid(head).kid(body)))
     }
 }
 
@@ -56,3 +167,93 @@ impl Default for HtmlDocumentBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::css::{px, Color, Property, Rule, ScopedStyle};
+    use crate::science_lab::NodeExt;
+    use crate::tags::{Div, Span, P};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn card() -> ScopedStyle {
+        ScopedStyle::new("Card", |scope| {
+            Stylesheet::new()
+                .rule(Rule::new(scope.selector("card")).set(Property::Padding, px(8)))
+                .rule(Rule::new(scope.selector("title")).set(Property::Color, Color::Orange))
+        })
+    }
+
+    fn byline() -> ScopedStyle {
+        ScopedStyle::new("Byline", |scope| {
+            Stylesheet::new()
+                .rule(Rule::new(scope.selector("by")).set(Property::Color, Color::Gray))
+        })
+    }
+
+    fn unused() -> ScopedStyle {
+        ScopedStyle::new("Unused", |scope| {
+            Stylesheet::new().rule(Rule::new(scope.selector("x")).set(Property::Color, Color::Red))
+        })
+    }
+
+    fn page() -> Node {
+        let (card, byline) = (card(), byline());
+        let _unused = unused();
+
+        let story = |title: &str| {
+            Div.scoped_class(&card, "card")
+                .kid(P.scoped_class(&card, "title").text(title))
+                .kid(Span.scoped_class(&byline, "by").text("by pg"))
+        };
+
+        Body.kid(story("One")).kid(story("Two"))
+    }
+
+    #[test]
+    fn collects_used_styles_once_into_head() {
+        let (card, byline) = (card(), byline());
+
+        let result = HtmlDocumentBuilder::new()
+            .with_head(o(Head))
+            .with_body(page())
+            .build()
+            .render_string()
+            .unwrap();
+
+        let expected_style = format!(
+            "<head><style>.{}{{padding:8px}}.{}{{color:orange}}.{}{{color:gray}}</style></head>",
+            card.class("card"),
+            card.class("title"),
+            byline.class("by")
+        );
+
+        assert!(result.contains(&expected_style), "{result}");
+        assert_eq!(1, result.matches("<style>").count());
+        assert!(!result.contains(&unused().class("x")));
+    }
+
+    #[test]
+    fn writes_external_stylesheet() {
+        let path = std::env::temp_dir().join("html-strong-scoped-styles-test.css");
+
+        let result = HtmlDocumentBuilder::new()
+            .with_head(o(Head))
+            .with_body(page())
+            .with_style_mode(StyleMode::External {
+                path: path.clone(),
+                href: "/static/components.css".into(),
+            })
+            .try_build()
+            .unwrap()
+            .render_string()
+            .unwrap();
+
+        assert!(result.contains(
+            r#"<head><link rel="stylesheet" type="text/css" href="/static/components.css"></head>"#
+        ));
+        let css = std::fs::read_to_string(&path).unwrap();
+        assert!(css.starts_with(&format!(".{}{{padding:8px}}", card().class("card"))));
+    }
+}